@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+
+/// File name of the answer registry in the workspace root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Answers recorded for one puzzle input.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Recorded {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+}
+
+/// Known good answers, keyed by day and the hash of the puzzle input (see
+/// `aoc_common::input_hash`), so the inputs themselves don't need to be checked in.
+/// In the file, every input gets a `[dayN.<input hash>]` table with `part1` and `part2`.
+#[derive(Debug, Default)]
+pub struct Answers {
+    path: PathBuf,
+    entries: BTreeMap<(u8, String), Recorded>,
+}
+
+impl Answers {
+    /// Load the registry, which is empty if the file doesn't exist yet.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            result => result.with_context(|| format!("Couldn't read {}", path.display()))?,
+        };
+        let entries =
+            parse_entries(&text).with_context(|| format!("Couldn't parse {}", path.display()))?;
+        Ok(Self { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Recorded answer to `part` (1 or 2) of `day` for the input with the given hash.
+    pub fn get(&self, day: u8, input_hash: &str, part: u8) -> Option<&Answer> {
+        let recorded = self.entries.get(&(day, input_hash.to_owned()))?;
+        match part {
+            1 => recorded.part1.as_ref(),
+            _ => recorded.part2.as_ref(),
+        }
+    }
+
+    /// Record an answer, replacing what was there before.
+    pub fn insert(&mut self, day: u8, input_hash: &str, part: u8, answer: Answer) {
+        let recorded = self
+            .entries
+            .entry((day, input_hash.to_owned()))
+            .or_default();
+        match part {
+            1 => recorded.part1 = Some(answer),
+            _ => recorded.part2 = Some(answer),
+        }
+    }
+
+    /// Write the registry back, ordered by day.
+    pub fn save(&self) -> Result<()> {
+        let mut days = toml::Table::new();
+        for ((day, input_hash), recorded) in &self.entries {
+            let inputs = days
+                .entry(format!("day{day}"))
+                .or_insert_with(|| toml::Table::new().into());
+            if let toml::Value::Table(inputs) = inputs {
+                inputs.insert(input_hash.clone(), toml::Value::try_from(recorded)?);
+            }
+        }
+        let text = format!(
+            "# Known good answers, see `aoc verify`. Keyed by day and the SHA-256 of the input.\n\n{}",
+            toml::to_string(&days)?
+        );
+        fs::write(&self.path, text)
+            .with_context(|| format!("Couldn't write {}", self.path.display()))
+    }
+}
+
+fn parse_entries(text: &str) -> Result<BTreeMap<(u8, String), Recorded>> {
+    let days: BTreeMap<String, BTreeMap<String, Recorded>> = toml::from_str(text)?;
+    let mut entries = BTreeMap::new();
+    for (day_key, inputs) in days {
+        let day = day_key
+            .strip_prefix("day")
+            .and_then(|day| day.parse().ok())
+            .ok_or_else(|| anyhow!("Expected a table like [day7], found [{day_key}]."))?;
+        for (input_hash, recorded) in inputs {
+            entries.insert((day, input_hash), recorded);
+        }
+    }
+    Ok(entries)
+}
+
+#[test]
+fn round_trip_in_day_order() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+    let mut answers = Answers {
+        path: path.clone(),
+        ..Default::default()
+    };
+    answers.insert(10, "ab12", 2, Answer::Text("#..#\n.##.".to_owned()));
+    answers.insert(9, "cd34", 1, Answer::Number(13));
+    answers.insert(9, "cd34", 2, Answer::Number(1));
+    answers.save().unwrap();
+
+    let text = fs::read_to_string(&path).unwrap();
+    assert!(text.find("[day9.cd34]").unwrap() < text.find("[day10.ab12]").unwrap());
+    let loaded = Answers::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.entries, answers.entries);
+    assert_eq!(loaded.get(9, "cd34", 1), Some(&Answer::Number(13)));
+    assert_eq!(loaded.get(10, "ab12", 1), None);
+}
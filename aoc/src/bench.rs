@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use aoc_common::{Config, DayResult, InputArgs};
+
+use crate::days::Day;
+
+/// Spread of the timings of one phase over all repetitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Statistics of a non-empty list of timings.
+    pub fn new(mut timings: Vec<Duration>) -> Self {
+        timings.sort();
+        let mid = timings.len() / 2;
+        let median = if timings.len().is_multiple_of(2) {
+            (timings[mid - 1] + timings[mid]) / 2
+        } else {
+            timings[mid]
+        };
+        Self {
+            min: timings[0],
+            median,
+            max: timings[timings.len() - 1],
+        }
+    }
+}
+
+/// Timings of all phases of one day.
+struct DayStats {
+    parse: Stats,
+    part1: Stats,
+    part2: Stats,
+}
+
+impl DayStats {
+    fn new(results: &[DayResult]) -> Self {
+        let phase =
+            |time: fn(&DayResult) -> Duration| Stats::new(results.iter().map(time).collect());
+        Self {
+            parse: phase(|result| result.parse_time),
+            part1: phase(|result| result.parts[0].elapsed),
+            part2: phase(|result| result.parts[1].elapsed),
+        }
+    }
+
+    /// Sum of the median of each phase.
+    fn total(&self) -> Duration {
+        self.parse.median + self.part1.median + self.part2.median
+    }
+}
+
+/// Solve each selected day `repetitions` times and print a table with the min, median
+/// and max time of parsing and of both parts. Days are printed as soon as they are done,
+/// so slow ones don't keep the rest of the table from showing up.
+pub fn bench(
+    selected: &[&Day],
+    input: &InputArgs,
+    config: &Config,
+    repetitions: usize,
+) -> Result<()> {
+    println!("Timings over {repetitions} run(s), min / median / max:");
+    println!();
+    println!(
+        "{:>3}  {:^26}  {:^26}  {:^26}  {:>8}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    let mut year_total = Duration::ZERO;
+    let mut nr_failed = 0;
+    for day in selected {
+        match bench_day(day, input, config, repetitions) {
+            Ok(stats) => {
+                year_total += stats.total();
+                println!(
+                    "{:>3}  {}  {}  {}  {:>8}",
+                    day.day,
+                    format_stats(&stats.parse),
+                    format_stats(&stats.part1),
+                    format_stats(&stats.part2),
+                    format_duration(stats.total())
+                );
+            }
+            Err(err) => {
+                println!(
+                    "{:>3}  error: {}",
+                    day.day,
+                    format!("{err:#}").lines().next().unwrap_or_default()
+                );
+                nr_failed += 1;
+            }
+        }
+    }
+    println!("{:>3}  {:>92}", "All", format_duration(year_total));
+    if nr_failed > 0 {
+        bail!("{nr_failed} day(s) failed.");
+    }
+    Ok(())
+}
+
+fn bench_day(
+    day: &Day,
+    input: &InputArgs,
+    config: &Config,
+    repetitions: usize,
+) -> Result<DayStats> {
+    let input = input.read(day.dir())?;
+    let results = (0..repetitions)
+        .map(|_| (day.solve)(&input, config))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DayStats::new(&results))
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "{:>8} {:>8} {:>8}",
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.max)
+    )
+}
+
+/// Duration with three significant digits and a fitting unit, e.g. "12.3µs" or "1.05s".
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    let (value, unit) = if secs >= 1.0 {
+        (secs, "s")
+    } else if secs >= 1e-3 {
+        (secs * 1e3, "ms")
+    } else if secs >= 1e-6 {
+        (secs * 1e6, "µs")
+    } else {
+        (secs * 1e9, "ns")
+    };
+    // nanoseconds are the resolution of the clock anyway
+    let decimals = match value {
+        _ if unit == "ns" => 0,
+        v if v >= 100.0 => 0,
+        v if v >= 10.0 => 1,
+        _ => 2,
+    };
+    format!("{value:.decimals$}{unit}")
+}
+
+#[test]
+fn stats_and_durations() {
+    let ms = Duration::from_millis;
+    let stats = Stats::new(vec![ms(5), ms(1), ms(3)]);
+    assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(5)));
+    assert_eq!(Stats::new(vec![ms(4), ms(1), ms(2), ms(9)]).median, ms(3));
+    assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+    assert_eq!(format_duration(Duration::from_millis(1_050)), "1.05s");
+    assert_eq!(format_duration(Duration::from_millis(250)), "250ms");
+    assert_eq!(format_duration(Duration::from_nanos(7)), "7ns");
+}
@@ -0,0 +1,88 @@
+use anyhow::{bail, Result};
+use aoc_common::{Answer, Config};
+
+use crate::days::Day;
+
+/// Check the solutions of the selected days against their oracles, on `runs` generated
+/// inputs each (seeds `seed`, `seed + 1`, ...). Days without an oracle are skipped.
+/// Disagreements are listed with their seed, so the input can be generated again.
+pub fn crosscheck(
+    selected: &[&Day],
+    runs: u64,
+    size: usize,
+    seed: u64,
+    config: &Config,
+) -> Result<()> {
+    let checked = selected
+        .iter()
+        .filter(|day| day.oracle.is_some())
+        .collect::<Vec<_>>();
+    if checked.is_empty() {
+        bail!("None of the selected days has an oracle.");
+    }
+    let mut nr_disagreements = 0;
+    for day in checked {
+        let mut notes = Vec::new();
+        for seed in seed..seed + runs {
+            for note in check_input(day, seed, size, config)? {
+                notes.push(format!("seed {seed}: {note}"));
+            }
+        }
+        if notes.is_empty() {
+            println!("Day {}: {runs} input(s) agree", day.day);
+        } else {
+            println!("Day {}: {} disagreement(s)", day.day, notes.len());
+            for note in &notes {
+                println!("  {note}");
+            }
+        }
+        nr_disagreements += notes.len();
+    }
+    if nr_disagreements > 0 {
+        bail!("{nr_disagreements} disagreement(s) between solutions and oracles.");
+    }
+    Ok(())
+}
+
+/// Generate an input, then solve it and ask the oracle. Returns what they disagree on.
+/// Parts the oracle leaves unsolved aren't compared, so a failing solution only disagrees
+/// if the oracle solves a part. If both fail, they agree.
+fn check_input(day: &Day, seed: u64, size: usize, config: &Config) -> Result<Vec<String>> {
+    let Some(oracle) = day.oracle else {
+        return Ok(Vec::new());
+    };
+    let input = (day.generate)(seed, size, config)?;
+    let notes = match ((day.solve)(&input, config), oracle(&input, config)) {
+        (Ok(result), Ok(expected)) => (1..)
+            .zip(result.parts.iter().zip(&expected))
+            .filter(|(_part, (part_result, expected))| {
+                **expected != Answer::Unsolved && part_result.answer != **expected
+            })
+            .map(|(part, (part_result, expected))| {
+                format!("part {part}: {}, oracle {expected}", part_result.answer)
+            })
+            .collect(),
+        (Err(err), Ok(expected)) if expected.iter().any(|answer| *answer != Answer::Unsolved) => {
+            vec![format!("error: {err:#}, oracle solves it")]
+        }
+        (Err(_), Ok(_)) => Vec::new(),
+        (Ok(_), Err(err)) => vec![format!("oracle error: {err:#}")],
+        (Err(_), Err(_)) => Vec::new(),
+    };
+    Ok(notes)
+}
+
+/// The days with a deterministic oracle agree with their solutions on a few inputs.
+#[test]
+fn solutions_agree_with_oracles() {
+    let config = aoc_common::ConfigArgs::default()
+        .load(crate::days::workspace_dir(), true)
+        .unwrap();
+    for day in [12, 15] {
+        let day = crate::days::find(day).unwrap();
+        for seed in 0..5 {
+            let notes = check_input(day, seed, 10, &config).unwrap();
+            assert!(notes.is_empty(), "Day {}, seed {seed}: {notes:?}", day.day);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use aoc_common::{
+    consult, generate, render, show, solve, Answer, Config, DayResult, GenerateInput, Oracle,
+    Solution, TerminalRenderer, Visualize,
+};
+
+/// Ask the oracle of a day about an input, see [`Oracle`].
+pub type ConsultFn = fn(&str, &Config) -> Result<[Answer; 2]>;
+
+/// Visualise a day for an input and write the frames to a file, see [`Visualize`].
+pub type RenderFn = fn(&str, &Config, &Path) -> Result<()>;
+
+/// Visualise a day for an input in the terminal, animated or not.
+pub type ShowFn = fn(&str, &Config, &TerminalRenderer, bool) -> Result<()>;
+
+/// Entry of the day registry: which day it is, how to solve it, and how to generate
+/// inputs for it (from a seed and a size). Some days also have an oracle, a second
+/// implementation to cross-check the solution with, or can be visualised.
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(&str, &Config) -> Result<DayResult>,
+    pub generate: fn(u64, usize, &Config) -> Result<String>,
+    pub oracle: Option<ConsultFn>,
+    pub visualizer: Option<Visualizer>,
+}
+
+/// How to visualise a day: rendered to a file, or in the terminal.
+#[derive(Clone, Copy)]
+pub struct Visualizer {
+    pub render: RenderFn,
+    pub show: ShowFn,
+}
+
+impl Day {
+    const fn new<S: GenerateInput>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve_with_config::<S>,
+            generate: generate_with_config::<S>,
+            oracle: None,
+            visualizer: None,
+        }
+    }
+
+    const fn with_oracle<S: Oracle>(self) -> Self {
+        Self {
+            oracle: Some(consult_with_config::<S>),
+            ..self
+        }
+    }
+
+    const fn with_visualize<S: Visualize>(self) -> Self {
+        Self {
+            visualizer: Some(Visualizer {
+                render: render_with_config::<S>,
+                show: show_with_config::<S>,
+            }),
+            ..self
+        }
+    }
+
+    /// Directory of the day crate, which is where the puzzle input lives.
+    pub fn dir(&self) -> PathBuf {
+        workspace_dir().join(format!("p{}", self.day))
+    }
+}
+
+/// Look up the parameters of the day in the config, then solve it.
+fn solve_with_config<S: Solution>(input: &str, config: &Config) -> Result<DayResult> {
+    solve::<S>(input, &config.params(S::DAY)?)
+}
+
+/// Look up the parameters of the day in the config, then generate an input for them.
+fn generate_with_config<S: GenerateInput>(
+    seed: u64,
+    size: usize,
+    config: &Config,
+) -> Result<String> {
+    Ok(generate::<S>(seed, size, &config.params(S::DAY)?))
+}
+
+/// Look up the parameters of the day in the config, then ask its oracle.
+fn consult_with_config<S: Oracle>(input: &str, config: &Config) -> Result<[Answer; 2]> {
+    consult::<S>(input, &config.params(S::DAY)?)
+}
+
+/// Look up the parameters of the day in the config, then render it to `path`.
+fn render_with_config<S: Visualize>(input: &str, config: &Config, path: &Path) -> Result<()> {
+    render::<S>(input, &config.params(S::DAY)?, path)
+}
+
+/// Look up the parameters of the day in the config, then draw it in the terminal.
+fn show_with_config<S: Visualize>(
+    input: &str,
+    config: &Config,
+    renderer: &TerminalRenderer,
+    animate: bool,
+) -> Result<()> {
+    show::<S>(input, &config.params(S::DAY)?, renderer, animate)
+}
+
+/// Root of the workspace, which is where aoc.toml lives.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace")
+}
+
+/// All days known to the runner, in order.
+pub const DAYS: &[Day] = &[
+    Day::new::<p1::Day01>(),
+    Day::new::<p2::Day02>(),
+    Day::new::<p3::Day03>(),
+    Day::new::<p4::Day04>(),
+    Day::new::<p5::Day05>(),
+    Day::new::<p6::Day06>(),
+    Day::new::<p7::Day07>(),
+    Day::new::<p8::Day08>().with_visualize::<p8::Day08>(),
+    Day::new::<p9::Day09>().with_visualize::<p9::Day09>(),
+    Day::new::<p10::Day10>().with_visualize::<p10::Day10>(),
+    Day::new::<p11::Day11>(),
+    Day::new::<p12::Day12>()
+        .with_oracle::<p12::Day12>()
+        .with_visualize::<p12::Day12>(),
+    Day::new::<p13::Day13>(),
+    Day::new::<p14::Day14>().with_visualize::<p14::Day14>(),
+    Day::new::<p15::Day15>().with_oracle::<p15::Day15>(),
+    Day::new::<p16::Day16>().with_oracle::<p16::Day16>(),
+];
+
+/// Look up a day in the registry.
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
+
+/// Every day is tested against its examples, see `aoc_common::example_tests!`. A day
+/// missing from the example registry would pass without checking anything.
+#[test]
+fn every_day_has_examples() {
+    let registry = std::fs::read_to_string(workspace_dir().join(aoc_common::EXAMPLES_FILE));
+    let registry = registry.unwrap();
+    for day in DAYS {
+        let examples = aoc_common::examples(&registry, day.day).unwrap();
+        assert!(!examples.is_empty(), "Day {} has no examples.", day.day);
+    }
+}
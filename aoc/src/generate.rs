@@ -0,0 +1,50 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use aoc_common::Config;
+use tracing::info;
+
+use crate::days::Day;
+
+/// Generate an input for `day` and write it to `output`, or to stdout. Without a seed,
+/// a random one is used and logged, so the input can be generated again.
+pub fn generate(
+    day: &Day,
+    size: usize,
+    seed: Option<u64>,
+    config: &Config,
+    output: Option<&Path>,
+) -> Result<()> {
+    let seed = seed.unwrap_or_else(|| {
+        let seed = rand::random();
+        info!("Generating day {} with seed {seed}.", day.day);
+        seed
+    });
+    let input = (day.generate)(seed, size, config)?;
+    match output {
+        Some(path) => {
+            fs::write(path, input).with_context(|| format!("Couldn't write {}", path.display()))
+        }
+        None => Ok(io::stdout().write_all(input.as_bytes())?),
+    }
+}
+
+/// Generated inputs solve for every day, and the same seed gives the same input.
+#[test]
+fn generated_inputs_solve() {
+    let config = aoc_common::ConfigArgs::default()
+        .load(crate::days::workspace_dir(), true)
+        .unwrap();
+    for day in crate::days::DAYS {
+        let input = (day.generate)(7, 20, &config).unwrap();
+        assert_eq!((day.generate)(7, 20, &config).unwrap(), input);
+        if let Err(err) = (day.solve)(&input, &config) {
+            panic!(
+                "Day {} failed on the generated input: {err:#}\n{input}",
+                day.day
+            );
+        }
+    }
+}
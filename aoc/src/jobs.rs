@@ -0,0 +1,157 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{input_hash, Config, DayResult, InputArgs};
+
+use crate::days::Day;
+
+/// Outcome of solving a day: the hash of its input along with the result.
+pub type Solved = Result<(String, DayResult)>;
+
+/// Solve the selected days on a pool of `jobs` threads and hand each outcome to `report`,
+/// in the order of `selected`: a day is reported as soon as it and all days before it
+/// are done. Each day is still timed on its own, though days running side by side may
+/// slow each other down. With a single job, days are solved one after the other and
+/// `start` is called right before each, so slow days show what they are working on;
+/// otherwise it's called right before reporting.
+pub fn solve_in_order(
+    selected: &[&Day],
+    input: &InputArgs,
+    config: &Config,
+    jobs: usize,
+    mut start: impl FnMut(&Day),
+    mut report: impl FnMut(&Day, Solved) -> Result<()>,
+) -> Result<()> {
+    if jobs <= 1 {
+        for day in selected {
+            start(day);
+            report(day, solve_day(day, input, config))?;
+        }
+        return Ok(());
+    }
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+    let (sender, receiver) = mpsc::channel();
+    pool.in_place_scope(|scope| {
+        for (idx, &day) in selected.iter().enumerate() {
+            let sender = sender.clone();
+            scope.spawn(move |_| {
+                // the receiver only goes away if reporting failed, then nobody's interested
+                let _ = sender.send((idx, solve_day(day, input, config)));
+            });
+        }
+        drop(sender);
+        let mut done = BTreeMap::new();
+        let mut next = 0;
+        for (idx, solved) in receiver {
+            done.insert(idx, solved);
+            while let Some(solved) = done.remove(&next) {
+                start(selected[next]);
+                report(selected[next], solved)?;
+                next += 1;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Solve a day, returning the hash of its input along with the result. A panic is caught
+/// and counts as the day failing, so it doesn't take the other days down with it.
+fn solve_day(day: &Day, input: &InputArgs, config: &Config) -> Solved {
+    let input = input.read(day.dir())?;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(&input, config)))
+        .map_err(|payload| anyhow!("Panicked: {}", panic_message(payload.as_ref())))??;
+    Ok((input_hash(&input), result))
+}
+
+/// The message a panic was raised with, if it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("no message", String::as_str),
+    }
+}
+
+/// Days solved side by side come out in order, with the same answers as one by one.
+#[test]
+fn parallel_days_are_reported_in_order() {
+    let input = InputArgs {
+        example: true,
+        ..InputArgs::default()
+    };
+    let config = aoc_common::ConfigArgs::default()
+        .load(crate::days::workspace_dir(), true)
+        .unwrap();
+    let selected = [1, 4, 2, 9, 3].map(|day| crate::days::find(day).unwrap());
+    let answers = |jobs| {
+        let mut answers = Vec::new();
+        solve_in_order(
+            &selected,
+            &input,
+            &config,
+            jobs,
+            |_| {},
+            |day, solved| {
+                let (_hash, result) = solved?;
+                answers.push((day.day, result.parts.map(|part| part.answer)));
+                Ok(())
+            },
+        )
+        .unwrap();
+        answers
+    };
+    let sequential = answers(1);
+    assert_eq!(
+        sequential.iter().map(|(day, _)| *day).collect::<Vec<_>>(),
+        [1, 4, 2, 9, 3]
+    );
+    assert_eq!(answers(3), sequential);
+}
+
+/// A panicking day fails on its own, and the days around it are still solved.
+#[test]
+fn panicking_day_fails_alone() {
+    let input = InputArgs {
+        example: true,
+        ..InputArgs::default()
+    };
+    let config = aoc_common::ConfigArgs::default()
+        .load(crate::days::workspace_dir(), true)
+        .unwrap();
+    let panicking = Day {
+        solve: |_input, _config| panic!("out of bananas"),
+        ..*crate::days::find(2).unwrap()
+    };
+    let selected = [
+        crate::days::find(1).unwrap(),
+        &panicking,
+        crate::days::find(3).unwrap(),
+    ];
+    for jobs in [1, 3] {
+        let mut outcomes = Vec::new();
+        solve_in_order(
+            &selected,
+            &input,
+            &config,
+            jobs,
+            |_| {},
+            |day, solved| {
+                outcomes.push((day.day, solved.map_err(|err| err.to_string()).map(|_| ())));
+                Ok(())
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            outcomes,
+            [
+                (1, Ok(())),
+                (2, Err("Panicked: out of bananas".to_owned())),
+                (3, Ok(()))
+            ]
+        );
+    }
+}
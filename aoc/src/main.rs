@@ -0,0 +1,306 @@
+//! Runner for all the Advent of Code 2022 days in this workspace.
+//!
+//! Examples: `aoc run 7`, `aoc run 1..=16`, `aoc run --all`, `aoc run 7 --example`,
+//! `aoc run 7 --input - < input.txt`, `aoc run 11 --set rounds_part2=500`,
+//! `aoc run --all --json`, `aoc run --all --jobs 4`, `aoc run 9 --render rope.gif`,
+//! `aoc run 14 --watch`, `aoc verify`, `aoc verify 9 --record`, `aoc bench -n 10`,
+//! `aoc generate 15 --size 30 --seed 1 -o big.txt`, `aoc crosscheck 12 --runs 100`,
+//! `aoc new 17 --part1 3068`.
+//! Diagnostics go to stderr and are tuned with `-v`/`-q` or `RUST_LOG`, e.g.
+//! `aoc run 15 -v` or `RUST_LOG=p15=trace aoc run 15`.
+mod answers;
+mod bench;
+mod crosscheck;
+mod days;
+mod generate;
+mod jobs;
+mod output;
+mod scaffold;
+mod verify;
+
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::{Answer, Config, ConfigArgs, InputArgs, LogArgs, TerminalRenderer};
+use clap::{Parser, Subcommand};
+use tracing::info;
+
+use crate::days::{Day, Visualizer};
+use crate::output::Output;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days.
+    Run {
+        /// Day or range of days, e.g. "7", "1..=16" or "3..6".
+        #[arg(value_parser = parse_days, required_unless_present = "all")]
+        days: Option<RangeInclusive<u8>>,
+        /// Solve all available days.
+        #[arg(long, conflicts_with = "days")]
+        all: bool,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        config: ConfigArgs,
+        /// Print one JSON object per line and part (day, part, answer, elapsed_us, input_hash).
+        #[arg(long)]
+        json: bool,
+        /// Also render the day to this file: the final picture for ".png" and ".svg", an
+        /// animation for ".gif". Only for a single day, and only some days can be rendered.
+        #[arg(long, value_name = "PATH")]
+        render: Option<PathBuf>,
+        /// Also draw the day in the terminal, as it ends up. Like --render, only for some days.
+        #[arg(long, conflicts_with = "json")]
+        show: bool,
+        /// Like --show, but animated.
+        #[arg(long, conflicts_with_all = ["json", "show"])]
+        watch: bool,
+        /// Solve up to this many days at the same time. The output stays in day order.
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+    },
+    /// Time parsing and both parts of each day, and print a table for the whole year.
+    Bench {
+        /// Day or range of days, e.g. "7", "1..=16" or "3..6". All days if left out.
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+        /// Number of times each day is solved.
+        #[arg(short = 'n', long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        repetitions: u32,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Solve days for all their stored inputs and compare with the answers in answers.toml.
+    Verify {
+        /// Day or range of days, e.g. "7", "1..=16" or "3..6". All days if left out.
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+        /// Add answers missing from answers.toml instead of skipping those inputs.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Write a random puzzle input for a day, for stress tests and benchmarks.
+    Generate {
+        /// Day to generate an input for.
+        day: u8,
+        /// Size of the input, e.g. the number of elves for day 1 or of sensors for day 15.
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Seed of the random generator. A random seed is used (and printed) if left out.
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Generate for the parameters of the example ([dayN.example] in aoc.toml).
+        #[arg(long)]
+        example: bool,
+        /// Write the input to this file instead of stdout.
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Compare solutions with their slow oracles on generated inputs, with the parameters
+    /// of the example ([dayN.example] in aoc.toml).
+    Crosscheck {
+        /// Day or range of days, e.g. "12" or "12..=16". All days with an oracle if left out.
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+        /// Number of inputs generated for each day.
+        #[arg(long, default_value_t = 20)]
+        runs: u64,
+        /// Size of the inputs, see `aoc generate`.
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+        /// Seed of the first input; the others follow in order.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Create the crate of a new day from a template and register it with the runner.
+    New {
+        /// Day to create, from 1 to 25.
+        day: u8,
+        /// Answer to part 1 of the example, recorded in examples.toml for the new day to be tested on.
+        #[arg(long, value_parser = scaffold::parse_answer)]
+        part1: Option<Answer>,
+        /// Answer to part 2 of the example.
+        #[arg(long, value_parser = scaffold::parse_answer)]
+        part2: Option<Answer>,
+    },
+}
+
+/// Parse a single day or a Rust-style range of days.
+fn parse_days(spec: &str) -> Result<RangeInclusive<u8>> {
+    let range = if let Some((start, end)) = spec.split_once("..=") {
+        start.parse()?..=end.parse()?
+    } else if let Some((start, end)) = spec.split_once("..") {
+        let end: u8 = end.parse()?;
+        start.parse()?..=end.checked_sub(1).ok_or_else(|| anyhow!("Empty range."))?
+    } else {
+        let day = spec.parse()?;
+        day..=day
+    };
+    if range.is_empty() {
+        bail!("Empty range.");
+    }
+    Ok(range)
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    cli.log.init();
+
+    match cli.command {
+        Command::Run {
+            days,
+            all,
+            input,
+            config,
+            json,
+            render,
+            show,
+            watch,
+            jobs,
+        } => {
+            let selected = select_days(if all { None } else { days })?;
+            check_single_day_options(&selected, &input, &config)?;
+            let visualizer = if render.is_some() || show || watch {
+                Some(visualizer(&selected)?)
+            } else {
+                None
+            };
+            let config = config.load(days::workspace_dir(), input.example)?;
+            let output = if json { Output::Json } else { Output::Text };
+            run(&selected, &input, &config, output, jobs.into())?;
+            if let Some(visualizer) = visualizer {
+                let input = input.read(selected[0].dir())?;
+                if show || watch {
+                    (visualizer.show)(&input, &config, &TerminalRenderer::default(), watch)?;
+                }
+                if let Some(path) = render {
+                    (visualizer.render)(&input, &config, &path)?;
+                    info!("Rendered day {} to {}.", selected[0].day, path.display());
+                }
+            }
+            Ok(())
+        }
+        Command::Bench {
+            days,
+            repetitions,
+            input,
+            config,
+        } => {
+            let selected = select_days(days)?;
+            check_single_day_options(&selected, &input, &config)?;
+            let config = config.load(days::workspace_dir(), input.example)?;
+            bench::bench(&selected, &input, &config, repetitions as usize)
+        }
+        Command::Verify { days, record } => verify::verify(&select_days(days)?, record),
+        Command::Generate {
+            day,
+            size,
+            seed,
+            example,
+            output,
+            config,
+        } => {
+            let selected = select_days(Some(day..=day))?;
+            let config = config.load(days::workspace_dir(), example)?;
+            generate::generate(selected[0], size, seed, &config, output.as_deref())
+        }
+        Command::Crosscheck {
+            days,
+            runs,
+            size,
+            seed,
+            config,
+        } => {
+            let selected = select_days(days)?;
+            if config.has_unscoped_overrides() && selected.len() > 1 {
+                bail!("When checking several days, --set needs a day prefix, e.g. day15.row=10.");
+            }
+            let config = config.load(days::workspace_dir(), true)?;
+            crosscheck::crosscheck(&selected, runs, size, seed, &config)
+        }
+        Command::New { day, part1, part2 } => {
+            scaffold::new_day(days::workspace_dir(), day, part1.as_ref(), part2.as_ref())
+        }
+    }
+}
+
+/// Reject options that only make sense for a single day when solving several.
+fn check_single_day_options(
+    selected: &[&Day],
+    input: &InputArgs,
+    config: &ConfigArgs,
+) -> Result<()> {
+    if input.is_explicit() && selected.len() > 1 {
+        bail!("--input can only be used when solving a single day.");
+    }
+    if config.has_unscoped_overrides() && selected.len() > 1 {
+        bail!("When solving several days, --set needs a day prefix, e.g. day15.row=10.");
+    }
+    Ok(())
+}
+
+/// How to visualise the day selected for --render, --show or --watch.
+fn visualizer(selected: &[&Day]) -> Result<Visualizer> {
+    match selected {
+        [day] => day
+            .visualizer
+            .ok_or_else(|| anyhow!("Day {} has no visualisation.", day.day)),
+        _ => bail!("Visualisations are only available for a single day."),
+    }
+}
+
+/// Days in the given range, or all available days.
+fn select_days(days: Option<RangeInclusive<u8>>) -> Result<Vec<&'static Day>> {
+    match days {
+        None => Ok(days::DAYS.iter().collect()),
+        Some(range) => range
+            .map(|day| days::find(day).ok_or_else(|| anyhow!("Day {day} is not available.")))
+            .collect(),
+    }
+}
+
+/// Solve the selected days, `jobs` at a time, and print them in order. A failing day,
+/// even one that panics, doesn't stop the others.
+fn run(
+    selected: &[&Day],
+    input: &InputArgs,
+    config: &Config,
+    output: Output,
+    jobs: usize,
+) -> Result<()> {
+    let mut nr_failed = 0;
+    jobs::solve_in_order(
+        selected,
+        input,
+        config,
+        jobs,
+        |day| output.start_day(day.day),
+        |day, solved| match solved {
+            Ok((input_hash, result)) => output.day_solved(day.day, &input_hash, &result),
+            Err(err) => {
+                nr_failed += 1;
+                output.day_failed(day.day, &err)
+            }
+        },
+    )?;
+    if nr_failed > 0 {
+        bail!("{nr_failed} day(s) failed.");
+    }
+    Ok(())
+}
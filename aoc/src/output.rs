@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use aoc_common::{Answer, DayResult};
+use serde::Serialize;
+
+/// How the runner reports its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    /// Readable text, one block per day.
+    Text,
+    /// One JSON object per line and part, for scripts and dashboards.
+    Json,
+}
+
+/// JSON record of one solved part.
+#[derive(Serialize)]
+struct PartRecord<'a> {
+    day: u8,
+    part: u8,
+    /// Number or string, `null` for unsolved parts.
+    answer: &'a Answer,
+    /// Time spent on this part in microseconds, without parsing the input.
+    elapsed_us: u64,
+    /// SHA-256 of the puzzle input, see `aoc_common::input_hash`.
+    input_hash: &'a str,
+}
+
+/// JSON record of a day that couldn't be solved.
+#[derive(Serialize)]
+struct ErrorRecord<'a> {
+    day: u8,
+    error: &'a str,
+}
+
+impl Output {
+    /// Called before solving a day, so slow days show what they are working on.
+    pub fn start_day(self, day: u8) {
+        if self == Output::Text {
+            println!("Day {day}");
+        }
+    }
+
+    pub fn day_solved(self, day: u8, input_hash: &str, result: &DayResult) -> Result<()> {
+        for (part, part_result) in (1..).zip(&result.parts) {
+            match self {
+                Output::Text => print_answer(part, &part_result.answer),
+                Output::Json => {
+                    let record = PartRecord {
+                        day,
+                        part,
+                        answer: &part_result.answer,
+                        elapsed_us: micros(part_result.elapsed),
+                        input_hash,
+                    };
+                    println!("{}", serde_json::to_string(&record)?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn day_failed(self, day: u8, err: &anyhow::Error) -> Result<()> {
+        let err = format!("{err:#}");
+        match self {
+            Output::Text => {
+                // parse errors come with a multi-line snippet of the offending input
+                let mut lines = err.lines();
+                println!("  Error: {}", lines.next().unwrap_or_default());
+                for line in lines {
+                    println!("    {line}");
+                }
+            }
+            Output::Json => {
+                let record = ErrorRecord { day, error: &err };
+                println!("{}", serde_json::to_string(&record)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn micros(duration: Duration) -> u64 {
+    duration.as_micros().try_into().unwrap_or(u64::MAX)
+}
+
+/// Multi-line answers (like the CRT image of day 10) are printed below the part label.
+fn print_answer(part: u8, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("  Part {part}:");
+        for line in answer.lines() {
+            println!("    {line}");
+        }
+    } else {
+        println!("  Part {part}: {answer}");
+    }
+}
@@ -0,0 +1,181 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{Answer, EXAMPLES_FILE, EXAMPLE_FILE};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+
+/// Create the crate of a new day in `workspace_dir` from the templates, and register it
+/// with the workspace, the runner and the example registry. The examples test expects the
+/// given answers, or unsolved parts until they are known, just like the new solution.
+pub fn new_day(
+    workspace_dir: &Path,
+    day: u8,
+    part1: Option<&Answer>,
+    part2: Option<&Answer>,
+) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("There is no day {day} in the advent calendar.");
+    }
+    let crate_dir = workspace_dir.join(format!("p{day}"));
+    if crate_dir.exists() {
+        bail!("{} exists already.", crate_dir.display());
+    }
+
+    // register first, so nothing is left half done if the day is known already
+    let workspace_manifest = workspace_dir.join("Cargo.toml");
+    let runner_manifest = workspace_dir.join("aoc/Cargo.toml");
+    let registry = workspace_dir.join("aoc/src/days.rs");
+    let edits = [
+        (
+            &workspace_manifest,
+            ("\"p", "\","),
+            format!("    \"p{day}\","),
+        ),
+        (
+            &runner_manifest,
+            ("p", " = "),
+            format!("p{day} = {{ path = \"../p{day}\" }}"),
+        ),
+        (
+            &registry,
+            ("Day::new::<p", "::"),
+            format!("    Day::new::<p{day}::Day{day:02}>(),"),
+        ),
+    ]
+    .into_iter()
+    .map(|(path, marker, line)| {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read {}", path.display()))?;
+        let text = insert_in_day_order(&text, day, marker, &line)
+            .with_context(|| format!("Couldn't register day {day} in {}", path.display()))?;
+        Ok((path, text))
+    })
+    .collect::<Result<Vec<_>>>()?;
+    // a new day comes last, there's no need to look for its place in the registry
+    let examples_path = workspace_dir.join(EXAMPLES_FILE);
+    let mut examples = fs::read_to_string(&examples_path)
+        .with_context(|| format!("Couldn't read {}", examples_path.display()))?;
+    if examples.contains(&format!("\nday{day} = ")) {
+        bail!("Day {day} has examples already.");
+    }
+    examples.push_str(&example_entries(day, part1, part2));
+
+    let fill = |template: &str| fill_template(template, day);
+    fs::create_dir_all(crate_dir.join("src"))?;
+    fs::write(crate_dir.join("Cargo.toml"), fill(CARGO_TEMPLATE))?;
+    fs::write(crate_dir.join("src/lib.rs"), fill(LIB_TEMPLATE))?;
+    fs::write(crate_dir.join("src/main.rs"), fill(MAIN_TEMPLATE))?;
+    fs::write(crate_dir.join(EXAMPLE_FILE), "")?;
+    for (path, text) in edits.into_iter().chain([(&examples_path, examples)]) {
+        fs::write(path, text).with_context(|| format!("Couldn't write {}", path.display()))?;
+    }
+    println!(
+        "Created {}. Paste the example into {EXAMPLE_FILE} there, then solve it with \
+         `cargo run -p aoc -- run {day} --example`.",
+        crate_dir.display()
+    );
+    Ok(())
+}
+
+/// Replace the placeholders of a template.
+fn fill_template(template: &str, day: u8) -> String {
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{Day}}", &format!("Day{day:02}"))
+}
+
+/// Entries of the example registry for both parts of a new day, leaving out the answers
+/// that aren't known.
+fn example_entries(day: u8, part1: Option<&Answer>, part2: Option<&Answer>) -> String {
+    let entries = [(1, part1), (2, part2)].map(|(part, answer)| {
+        let answer = match answer {
+            Some(Answer::Number(number)) => format!(", answer = {number}"),
+            Some(Answer::Text(text)) => format!(", answer = {}", toml::Value::from(text.as_str())),
+            None | Some(Answer::Unsolved) => String::new(),
+        };
+        format!("    {{ file = \"{EXAMPLE_FILE}\", part = {part}{answer} }},\n")
+    });
+    format!("\nday{day} = [\n{}]\n", entries.concat())
+}
+
+/// Parse an answer given on the command line: a number if it looks like one, text otherwise.
+pub fn parse_answer(answer: &str) -> Result<Answer, std::convert::Infallible> {
+    Ok(answer
+        .parse::<i64>()
+        .map_or_else(|_| Answer::from(answer), Answer::Number))
+}
+
+/// Day a line like `"p7",` refers to, given the text before and after the number.
+fn day_of_line(line: &str, (prefix, suffix): (&str, &str)) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let digits_end = rest.find(|ch: char| !ch.is_ascii_digit())?;
+    rest[digits_end..]
+        .starts_with(suffix)
+        .then(|| rest[..digits_end].parse().ok())?
+}
+
+/// Insert `new_line` among the lines referring to days (recognised by `marker`, see
+/// [`day_of_line`]), keeping them in day order. Lines indented deeper than a day's line
+/// belong to it, like the builder calls of an entry of the registry.
+fn insert_in_day_order(
+    text: &str,
+    day: u8,
+    marker: (&str, &str),
+    new_line: &str,
+) -> Result<String> {
+    let lines = text.lines().collect::<Vec<_>>();
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let mut insert_at = None;
+    for (idx, line) in lines.iter().enumerate() {
+        match day_of_line(line, marker) {
+            Some(other) if other == day => bail!("Day {day} is registered already."),
+            Some(other) if other > day => {
+                insert_at = Some(idx);
+                break;
+            }
+            Some(_) => {
+                let continued = lines[idx + 1..]
+                    .iter()
+                    .take_while(|next| indent(next) > indent(line))
+                    .count();
+                insert_at = Some(idx + 1 + continued);
+            }
+            None => {}
+        }
+    }
+    let Some(insert_at) = insert_at else {
+        bail!("Found no other day to put it next to.");
+    };
+    let mut lines = lines;
+    lines.insert(insert_at, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[test]
+fn days_are_inserted_in_order() {
+    let registry = "pub const DAYS: &[Day] = &[\n    Day::new::<p1::Day01>(),\n    \
+                    Day::new::<p12::Day12>()\n        .with_oracle::<p12::Day12>(),\n];\n";
+    let marker = ("Day::new::<p", "::");
+    let text = insert_in_day_order(registry, 13, marker, "    Day::new::<p13::Day13>(),").unwrap();
+    assert!(text.ends_with(".with_oracle::<p12::Day12>(),\n    Day::new::<p13::Day13>(),\n];\n"));
+    let text = insert_in_day_order(&text, 2, marker, "    Day::new::<p2::Day02>(),").unwrap();
+    assert!(text.contains("<p1::Day01>(),\n    Day::new::<p2::Day02>(),\n    Day::new::<p12"));
+    assert!(insert_in_day_order(&text, 12, marker, "").is_err());
+
+    let manifest = "p9 = { path = \"../p9\" }\nrand = \"0.8.5\"\n";
+    let text = insert_in_day_order(manifest, 10, ("p", " = "), "p10 = { path = \"../p10\" }");
+    assert_eq!(
+        text.unwrap(),
+        "p9 = { path = \"../p9\" }\np10 = { path = \"../p10\" }\nrand = \"0.8.5\"\n"
+    );
+    let lib = fill_template(LIB_TEMPLATE, 17);
+    assert!(lib.contains("impl Solution for Day17 {"));
+    let entries = example_entries(17, Some(&Answer::Number(3068)), None);
+    let examples = aoc_common::examples(&entries, 17).unwrap();
+    assert_eq!(examples[0].answer, Some(Answer::Number(3068)));
+    assert_eq!((examples[1].part, &examples[1].answer), (2, &None));
+}
@@ -0,0 +1,114 @@
+use std::fs;
+use std::io;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{input_hash, Answer, ConfigArgs, EXAMPLE_FILE, INPUT_FILE};
+
+use crate::answers::{Answers, ANSWERS_FILE};
+use crate::days::{self, Day};
+
+/// What came out of checking one input against the registry.
+#[derive(Debug, Default)]
+struct Tally {
+    nr_checked: usize,
+    nr_failed: usize,
+    nr_recorded: usize,
+}
+
+/// Solve the selected days for every input stored in their directories (the personal
+/// input.txt and the example input_test.txt), and compare with the answer registry.
+/// With `record`, answers missing from the registry are added instead of being skipped.
+pub fn verify(selected: &[&Day], record: bool) -> Result<()> {
+    let mut answers = Answers::load(days::workspace_dir().join(ANSWERS_FILE))?;
+    let mut tally = Tally::default();
+    for day in selected {
+        println!("Day {}", day.day);
+        for (file_name, example) in [(INPUT_FILE, false), (EXAMPLE_FILE, true)] {
+            let path = day.dir().join(file_name);
+            let input = match fs::read_to_string(&path) {
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                result => result.with_context(|| format!("Couldn't read {}", path.display()))?,
+            };
+            let status = verify_input(day, &input, example, &mut answers, record, &mut tally)?;
+            println!("  {file_name}: {status}");
+        }
+    }
+    if tally.nr_recorded > 0 {
+        answers.save()?;
+        println!(
+            "Recorded {} answer(s) in {}.",
+            tally.nr_recorded,
+            answers.path().display()
+        );
+    }
+    if tally.nr_failed > 0 {
+        bail!(
+            "{} of {} input(s) failed.",
+            tally.nr_failed,
+            tally.nr_checked
+        );
+    }
+    Ok(())
+}
+
+/// Check (or record) the answers for one input, returning a one-line summary.
+fn verify_input(
+    day: &Day,
+    input: &str,
+    example: bool,
+    answers: &mut Answers,
+    record: bool,
+    tally: &mut Tally,
+) -> Result<String> {
+    let input_hash = input_hash(input);
+    if !record && (1..=2).all(|part| answers.get(day.day, &input_hash, part).is_none()) {
+        return Ok("no recorded answers, run with --record to add them".to_owned());
+    }
+    tally.nr_checked += 1;
+    let config = ConfigArgs::default().load(days::workspace_dir(), example)?;
+    let result = match (day.solve)(input, &config) {
+        Ok(result) => result,
+        Err(err) => {
+            tally.nr_failed += 1;
+            return Ok(format!("error: {err:#}"));
+        }
+    };
+
+    let mut notes = Vec::new();
+    let mut mismatch = false;
+    for (part, part_result) in (1..).zip(result.parts) {
+        match answers.get(day.day, &input_hash, part) {
+            Some(expected) if *expected == part_result.answer => {}
+            Some(expected) => {
+                mismatch = true;
+                notes.push(format!(
+                    "part {part} is {}, expected {}",
+                    quoted(&part_result.answer),
+                    quoted(expected)
+                ));
+            }
+            None if record && part_result.answer != Answer::Unsolved => {
+                notes.push(format!("recorded part {part}"));
+                answers.insert(day.day, &input_hash, part, part_result.answer);
+                tally.nr_recorded += 1;
+            }
+            None => {}
+        }
+    }
+    if mismatch {
+        tally.nr_failed += 1;
+    }
+    Ok(if notes.is_empty() {
+        "ok".to_owned()
+    } else {
+        notes.join(", ")
+    })
+}
+
+/// Answers in mismatch reports, quoted so that e.g. trailing spaces of day 5 stand out.
+fn quoted(answer: &Answer) -> String {
+    match answer {
+        Answer::Text(text) => format!("{text:?}"),
+        answer => answer.to_string(),
+    }
+}
@@ -0,0 +1,521 @@
+//! Parser combinators for puzzle input, in the spirit of the "nom" crate, but reporting
+//! errors as [`ParseError`]s pointing into the input line.
+//!
+//! A parser is any function taking the [`Span`] of a line that is left to parse and
+//! returning the rest along with what it parsed. The functions here either are parsers
+//! (like [`rest`]) or build them (like [`tag`], [`uint`] or [`separated_pair`]), and
+//! [`parse_line`] runs one on a whole line:
+//!
+//! ```
+//! use aoc_common::combinators::{parse_line, separated_pair, tag, uint};
+//! use aoc_common::InputLine;
+//!
+//! let range = || separated_pair(uint::<u32>("a section number"), tag("-"), uint("a section number"));
+//! let line = InputLine::new(1, "2-4,6-8");
+//! assert_eq!(parse_line(line, separated_pair(range(), tag(","), range())), Ok(((2, 4), (6, 8))));
+//! let err = parse_line(InputLine::new(2, "2-4,x-8"), separated_pair(range(), tag(","), range()));
+//! assert_eq!(err.unwrap_err().column, 5);
+//! ```
+//!
+//! Parsers commit once they got past the start of their input: [`opt`], [`alt`] and the
+//! lists only try something else if a parser failed right where it started, so errors
+//! point at the spot that is actually wrong.
+use std::str::FromStr;
+
+use crate::parse::{InputLine, ParseError};
+
+/// Rest of an input line that is still to be parsed. Knows the whole line, so errors
+/// can point at the right spot.
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    pub line: InputLine<'a>,
+    pub rest: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// The whole line, nothing parsed yet.
+    pub fn new(line: InputLine<'a>) -> Self {
+        Self {
+            line,
+            rest: line.text,
+        }
+    }
+
+    /// Take the first `len` bytes of the rest.
+    fn take(self, len: usize) -> (Self, &'a str) {
+        let (taken, rest) = self.rest.split_at(len);
+        (Self { rest, ..self }, taken)
+    }
+
+    /// Bytes of the line parsed so far.
+    fn offset(&self) -> usize {
+        self.line.text.len() - self.rest.len()
+    }
+
+    /// Whether `err` happened beyond the start of this span, i.e. a parser got somewhere
+    /// before failing.
+    fn is_behind(&self, err: &ParseError) -> bool {
+        err.line != self.line.nr || err.column > self.line.text[..self.offset()].chars().count() + 1
+    }
+
+    /// Error at the start of the rest, pointing at the next token: a word or number, or
+    /// a single other character.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest;
+        let len = match rest.find(|ch: char| !ch.is_alphanumeric()) {
+            Some(0) => rest.chars().next().map_or(0, char::len_utf8),
+            Some(len) => len,
+            None => rest.len(),
+        };
+        self.line.error_at(&rest[..len], expected)
+    }
+}
+
+/// Outcome of a parser: the rest of the line along with the parsed value.
+pub type PResult<'a, T> = Result<(Span<'a>, T), ParseError>;
+
+/// Anything that parses the start of a span. Implemented for all functions and
+/// closures of the right shape, which is what the combinators take and return.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Span<'a>) -> PResult<'a, T>;
+}
+
+impl<'a, T, F: Fn(Span<'a>) -> PResult<'a, T>> Parser<'a, T> for F {
+    fn parse(&self, input: Span<'a>) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Borrow a parser, to use it in another one without giving it away.
+fn by_ref<'p, 'a, T>(parser: &'p impl Parser<'a, T>) -> impl Fn(Span<'a>) -> PResult<'a, T> + 'p {
+    move |input| parser.parse(input)
+}
+
+/// Run `parser` on a whole line, which it has to use up.
+pub fn parse_line<'a, T>(line: InputLine<'a>, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    let (rest, value) = parser.parse(Span::new(line))?;
+    if !rest.rest.is_empty() {
+        return Err(rest.error("end of line"));
+    }
+    Ok(value)
+}
+
+/// Run `parser` on each of the lines, numbering them from 1.
+pub fn parse_lines<'a, T>(
+    lines: impl IntoIterator<Item = &'a str>,
+    parser: impl Parser<'a, T>,
+) -> Result<Vec<T>, ParseError> {
+    InputLine::numbered(lines)
+        .map(|line| parse_line(line, by_ref(&parser)))
+        .collect()
+}
+
+/// Exactly the given text.
+pub fn tag<'a>(tag: &'static str) -> impl Fn(Span<'a>) -> PResult<'a, &'a str> {
+    move |input| {
+        if input.rest.starts_with(tag) {
+            return Ok(input.take(tag.len()));
+        }
+        let mismatch = input.rest.len().min(tag.len());
+        let found = input.rest.get(..mismatch).unwrap_or(input.rest);
+        Err(input.line.error_at(found, format!("{tag:?}")))
+    }
+}
+
+/// The first of the given texts that comes next.
+pub fn one_of<'a>(
+    choices: &'static [&'static str],
+    expected: &'static str,
+) -> impl Fn(Span<'a>) -> PResult<'a, &'a str> {
+    move |input| {
+        choices
+            .iter()
+            .find(|choice| input.rest.starts_with(**choice))
+            .map(|choice| input.take(choice.len()))
+            .ok_or_else(|| input.error(expected))
+    }
+}
+
+/// A single character for which `predicate` holds.
+pub fn satisfy<'a>(
+    predicate: impl Fn(char) -> bool,
+    expected: &'static str,
+) -> impl Fn(Span<'a>) -> PResult<'a, char> {
+    move |input| match input.rest.chars().next() {
+        Some(ch) if predicate(ch) => Ok((input.take(ch.len_utf8()).0, ch)),
+        _ => Err(input.error(expected)),
+    }
+}
+
+/// The longest run of characters for which `predicate` holds, which may be empty.
+pub fn take_while<'a>(
+    predicate: impl Fn(char) -> bool,
+) -> impl Fn(Span<'a>) -> PResult<'a, &'a str> {
+    move |input| {
+        let len = input
+            .rest
+            .find(|ch: char| !predicate(ch))
+            .unwrap_or(input.rest.len());
+        Ok(input.take(len))
+    }
+}
+
+/// Like [`take_while`], but at least one character.
+pub fn take_while1<'a>(
+    predicate: impl Fn(char) -> bool,
+    expected: &'static str,
+) -> impl Fn(Span<'a>) -> PResult<'a, &'a str> {
+    let run = take_while(predicate);
+    move |input| match run(input)? {
+        (_, "") => Err(input.error(expected)),
+        ok => Ok(ok),
+    }
+}
+
+/// Any number of spaces, including none.
+pub fn space0(input: Span<'_>) -> PResult<'_, &str> {
+    take_while(|ch| ch == ' ')(input)
+}
+
+/// Whatever is left of the line.
+pub fn rest(input: Span<'_>) -> PResult<'_, &str> {
+    Ok(input.take(input.rest.len()))
+}
+
+/// A non-negative integer, e.g. "42".
+pub fn uint<'a, T: FromStr>(expected: &'static str) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    let digits = take_while1(|ch| ch.is_ascii_digit(), expected);
+    move |input| {
+        let (rest, digits) = digits(input)?;
+        let value = digits
+            .parse()
+            .map_err(|_| input.line.error_at(digits, expected))?;
+        Ok((rest, value))
+    }
+}
+
+/// An integer with an optional minus sign, e.g. "-42".
+pub fn int<'a, T: FromStr>(expected: &'static str) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    move |input: Span<'a>| {
+        let sign_len = usize::from(input.rest.starts_with('-'));
+        let (rest, _digits) =
+            take_while1(|ch| ch.is_ascii_digit(), expected)(input.take(sign_len).0)
+                .map_err(|_| input.error(expected))?;
+        let number = &input.rest[..input.rest.len() - rest.rest.len()];
+        let value = number
+            .parse()
+            .map_err(|_| input.line.error_at(number, expected))?;
+        Ok((rest, value))
+    }
+}
+
+/// A pair of coordinates like "x=-2, y=15".
+pub fn xy<'a, T: FromStr>() -> impl Fn(Span<'a>) -> PResult<'a, (T, T)> {
+    separated_pair(
+        preceded(tag("x="), int("an x coordinate")),
+        tag(", "),
+        preceded(tag("y="), int("a y coordinate")),
+    )
+}
+
+/// Transform the value of `parser`.
+pub fn map<'a, O, T>(
+    parser: impl Parser<'a, O>,
+    f: impl Fn(O) -> T,
+) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    move |input| parser.parse(input).map(|(rest, value)| (rest, f(value)))
+}
+
+/// Transform the value of `parser`, or reject it. The error points at what `parser`
+/// parsed, expecting what `f` returns as error.
+pub fn try_map<'a, O, T>(
+    parser: impl Parser<'a, O>,
+    f: impl Fn(O) -> Result<T, String>,
+) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    move |input| {
+        let (rest, (parsed, value)) = spanned(by_ref(&parser))(input)?;
+        let value = f(value).map_err(|expected| input.line.error_at(parsed, expected))?;
+        Ok((rest, value))
+    }
+}
+
+/// The value of `parser` along with the text it parsed.
+pub fn spanned<'a, T>(
+    parser: impl Parser<'a, T>,
+) -> impl Fn(Span<'a>) -> PResult<'a, (&'a str, T)> {
+    move |input| {
+        let (rest, value) = parser.parse(input)?;
+        let parsed = &input.rest[..input.rest.len() - rest.rest.len()];
+        Ok((rest, (parsed, value)))
+    }
+}
+
+/// `parser`, if it applies.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(Span<'a>) -> PResult<'a, Option<T>> {
+    move |input| match parser.parse(input) {
+        Ok((rest, value)) => Ok((rest, Some(value))),
+        Err(err) if input.is_behind(&err) => Err(err),
+        Err(_) => Ok((input, None)),
+    }
+}
+
+/// `first`, then `second`.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Fn(Span<'a>) -> PResult<'a, (A, B)> {
+    move |input| {
+        let (input, a) = first.parse(input)?;
+        let (input, b) = second.parse(input)?;
+        Ok((input, (a, b)))
+    }
+}
+
+/// `first` and `second` with `separator` in between, which is dropped.
+pub fn separated_pair<'a, A, S, B>(
+    first: impl Parser<'a, A>,
+    separator: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Fn(Span<'a>) -> PResult<'a, (A, B)> {
+    pair(first, preceded(separator, second))
+}
+
+/// `parser` after `prefix`, which is dropped.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    move |input| {
+        let (input, _prefix) = prefix.parse(input)?;
+        parser.parse(input)
+    }
+}
+
+/// `parser` followed by `suffix`, which is dropped.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    move |input| {
+        let (input, value) = parser.parse(input)?;
+        let (input, _suffix) = suffix.parse(input)?;
+        Ok((input, value))
+    }
+}
+
+/// `parser` between `open` and `close`, which are dropped.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// A value like "Starting items: 79, 98", possibly indented. The label is dropped, as is
+/// the space after the colon.
+pub fn labelled<'a, T>(
+    label: &'static str,
+    value: impl Parser<'a, T>,
+) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    preceded(
+        space0,
+        preceded(tag(label), preceded(tag(":"), preceded(space0, value))),
+    )
+}
+
+/// Any number of `item`s (including none) with `separator` in between. After a
+/// separator, another item has to follow.
+pub fn separated_list0<'a, S, T>(
+    separator: impl Parser<'a, S>,
+    item: impl Parser<'a, T>,
+) -> impl Fn(Span<'a>) -> PResult<'a, Vec<T>> {
+    move |input| separated_list(&separator, &item, input, false)
+}
+
+/// Like [`separated_list0`], but at least one item.
+pub fn separated_list1<'a, S, T>(
+    separator: impl Parser<'a, S>,
+    item: impl Parser<'a, T>,
+) -> impl Fn(Span<'a>) -> PResult<'a, Vec<T>> {
+    move |input| separated_list(&separator, &item, input, true)
+}
+
+fn separated_list<'a, S, T>(
+    separator: &impl Parser<'a, S>,
+    item: &impl Parser<'a, T>,
+    input: Span<'a>,
+    at_least_one: bool,
+) -> PResult<'a, Vec<T>> {
+    let (mut input, first) = if at_least_one {
+        let (input, first) = item.parse(input)?;
+        (input, Some(first))
+    } else {
+        opt(by_ref(item))(input)?
+    };
+    let Some(first) = first else {
+        return Ok((input, Vec::new()));
+    };
+    let mut items = vec![first];
+    while let (rest, Some(_separator)) = opt(by_ref(separator))(input)? {
+        let (rest, next) = item.parse(rest)?;
+        items.push(next);
+        input = rest;
+    }
+    Ok((input, items))
+}
+
+/// Items separated by a comma and a space, like "DD, II, BB". At least one.
+pub fn comma_list<'a, T>(item: impl Parser<'a, T>) -> impl Fn(Span<'a>) -> PResult<'a, Vec<T>> {
+    separated_list1(tag(", "), item)
+}
+
+/// `item` over and over until the line ends.
+pub fn repeat_to_end<'a, T>(item: impl Parser<'a, T>) -> impl Fn(Span<'a>) -> PResult<'a, Vec<T>> {
+    move |mut input| {
+        let mut items = Vec::new();
+        while !input.rest.is_empty() {
+            let (rest, next) = item.parse(input)?;
+            items.push(next);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// Alternative parsers, see [`alt`].
+pub trait Alt<'a, T> {
+    fn choice(&self, input: Span<'a>) -> PResult<'a, T>;
+}
+
+/// Of two errors, the one that got further. If they got equally far, the expectations
+/// of both.
+fn furthest(best: Option<ParseError>, err: ParseError) -> ParseError {
+    match best {
+        Some(best) if (best.line, best.column) > (err.line, err.column) => best,
+        Some(best) if (best.line, best.column) == (err.line, err.column) => ParseError {
+            expected: format!("{} or {}", best.expected, err.expected),
+            ..err
+        },
+        _ => err,
+    }
+}
+
+macro_rules! impl_alt {
+    ($($parser:ident $idx:tt),+) => {
+        impl<'a, T, $($parser: Parser<'a, T>),+> Alt<'a, T> for ($($parser,)+) {
+            fn choice(&self, input: Span<'a>) -> PResult<'a, T> {
+                let mut best = None;
+                $(
+                    match self.$idx.parse(input) {
+                        Ok(ok) => return Ok(ok),
+                        Err(err) if input.is_behind(&err) => return Err(err),
+                        Err(err) => best = Some(furthest(best, err)),
+                    }
+                )+
+                Err(best.expect("at least one alternative"))
+            }
+        }
+    };
+}
+
+impl_alt!(A 0, B 1);
+impl_alt!(A 0, B 1, C 2);
+impl_alt!(A 0, B 1, C 2, D 3);
+
+/// The first of a tuple of parsers that applies.
+pub fn alt<'a, T>(parsers: impl Alt<'a, T>) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    move |input| parsers.choice(input)
+}
+
+/// Lines between empty lines, like a monkey of day 11 or an elf of day 1, to be
+/// parsed one after the other.
+#[derive(Debug, Clone)]
+pub struct Block<'a> {
+    lines: Vec<InputLine<'a>>,
+    next: usize,
+}
+
+impl<'a> Block<'a> {
+    /// All lines of the block.
+    pub fn lines(&self) -> &[InputLine<'a>] {
+        &self.lines
+    }
+
+    /// Parse the next line of the block. If there is none, the error points just after
+    /// the block.
+    pub fn line<T>(&mut self, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+        let line = match self.lines.get(self.next) {
+            Some(&line) => line,
+            None => InputLine::new(self.lines.last().map_or(1, |line| line.nr + 1), ""),
+        };
+        self.next += 1;
+        parse_line(line, parser)
+    }
+
+    /// Make sure all lines of the block have been parsed.
+    pub fn finish(&self) -> Result<(), ParseError> {
+        match self.lines.get(self.next) {
+            Some(line) => Err(line.error("an empty line")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Split lines into blocks at empty lines, numbering them from 1. Several empty lines in
+/// a row don't make for empty blocks.
+pub fn blocks<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<Block<'a>> {
+    let lines = InputLine::numbered(lines).collect::<Vec<_>>();
+    lines
+        .split(|line| line.text.is_empty())
+        .filter(|block| !block.is_empty())
+        .map(|block| Block {
+            lines: block.to_vec(),
+            next: 0,
+        })
+        .collect()
+}
+
+#[test]
+fn lists_commit_after_separator() {
+    let line = InputLine::new(3, "79, 98, x");
+    let err = parse_line(line, comma_list(uint::<u32>("a worry level"))).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (3, 9, "x"));
+    let items = separated_list0(tag(","), uint::<u32>("a number"));
+    assert_eq!(parse_line(InputLine::new(1, ""), &items), Ok(vec![]));
+    assert_eq!(parse_line(InputLine::new(1, "1,2"), &items), Ok(vec![1, 2]));
+}
+
+#[test]
+fn alternatives_report_what_was_expected() {
+    let line = InputLine::new(1, "nop");
+    let instr = alt((
+        map(tag("noop"), |_| 0),
+        preceded(tag("addx "), int::<i32>("a number")),
+    ));
+    let err = parse_line(line, &instr).unwrap_err();
+    assert_eq!(err.expected, "\"noop\" or \"addx \"");
+    assert_eq!(parse_line(InputLine::new(2, "addx -7"), &instr), Ok(-7));
+    let err = parse_line(InputLine::new(3, "addx -"), &instr).unwrap_err();
+    assert_eq!((err.column, err.expected.as_str()), (6, "a number"));
+}
+
+#[test]
+fn coordinates_and_blocks() {
+    let line = InputLine::new(1, "Sensor at x=2, y=-18");
+    assert_eq!(
+        parse_line(line, preceded(tag("Sensor at "), xy())),
+        Ok((2, -18))
+    );
+    let mut blocks = blocks(["Monkey 0:", "  Test: divisible by 23", "", "", "Monkey 1:"]);
+    assert_eq!(blocks.len(), 2);
+    let monkey = blocks[0].line(delimited(tag("Monkey "), uint::<u32>("a number"), tag(":")));
+    assert_eq!(monkey, Ok(0));
+    let divisor = labelled(
+        "Test",
+        preceded(tag("divisible by "), uint::<u32>("a divisor")),
+    );
+    assert_eq!(blocks[0].line(&divisor), Ok(23));
+    assert_eq!(blocks[0].line(&divisor).unwrap_err().line, 3);
+    assert_eq!(blocks[1].finish().unwrap_err().line, 5);
+}
@@ -0,0 +1,219 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, Parser};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::input::InputArgs;
+use crate::logging::LogArgs;
+use crate::solution::Solution;
+
+/// File name of the puzzle parameters file in the workspace root.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Parameters of days that don't have any. Rejects every key, so typos in `aoc.toml`
+/// or `--set` don't go unnoticed.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// Command line options for the puzzle parameters, shared by the day binaries and the runner.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ConfigArgs {
+    /// Read the puzzle parameters from this file instead of aoc.toml in the workspace root.
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+    /// Override a puzzle parameter, e.g. "rounds_part2=500" or "day15.row=10". Can be repeated.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    pub overrides: Vec<Override>,
+}
+
+/// A `--set` parameter override. Without a `dayN.` prefix it applies to whichever day is solved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Override {
+    pub day: Option<u8>,
+    pub key: String,
+    pub value: toml::Value,
+}
+
+impl FromStr for Override {
+    type Err = anyhow::Error;
+
+    fn from_str(spec: &str) -> Result<Self> {
+        let (path, value) = spec
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Expected KEY=VALUE, found {spec:?}."))?;
+        let (day, key) = match path.trim().split_once('.') {
+            Some((day, key)) => {
+                let day = day
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse().ok())
+                    .ok_or_else(|| anyhow!("Expected a key like \"day15.row\", found {path:?}."))?;
+                (Some(day), key)
+            }
+            None => (None, path.trim()),
+        };
+        // Values are TOML ("10", "[20, 60]", "\"AA\""), but plain words are taken as
+        // strings so that `--set start_valve=BB` works without shell quoting gymnastics.
+        let value = format!("value = {}", value.trim())
+            .parse::<toml::Table>()
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.trim().to_owned()));
+        Ok(Self {
+            day,
+            key: key.to_owned(),
+            value,
+        })
+    }
+}
+
+impl ConfigArgs {
+    /// Load the parameters file, falling back to the built-in defaults of each day if
+    /// there is no aoc.toml. `example` selects the `[dayN.example]` sections as well.
+    pub fn load(&self, workspace_dir: impl AsRef<Path>, example: bool) -> Result<Config> {
+        let path = self
+            .config
+            .clone()
+            .unwrap_or_else(|| workspace_dir.as_ref().join(CONFIG_FILE));
+        let table = match fs::read_to_string(&path) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound && self.config.is_none() => {
+                toml::Table::new()
+            }
+            result => {
+                let text = result.with_context(|| format!("Couldn't read {}", path.display()))?;
+                text.parse()
+                    .with_context(|| format!("Couldn't parse {}", path.display()))?
+            }
+        };
+        Ok(Config {
+            path,
+            table,
+            overrides: self.overrides.clone(),
+            example,
+        })
+    }
+
+    /// Whether some override doesn't say which day it is meant for.
+    pub fn has_unscoped_overrides(&self) -> bool {
+        self.overrides.iter().any(|o| o.day.is_none())
+    }
+}
+
+/// Contents of aoc.toml together with the command line overrides.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    path: PathBuf,
+    table: toml::Table,
+    overrides: Vec<Override>,
+    example: bool,
+}
+
+impl Config {
+    /// Just the given overrides on top of the defaults of each day, without aoc.toml.
+    pub(crate) fn with_overrides(overrides: Vec<Override>) -> Self {
+        Self {
+            overrides,
+            ..Default::default()
+        }
+    }
+
+    /// Parameters of `day`: the `[dayN]` section, then `[dayN.example]` when solving the
+    /// example, then the `--set` overrides. Whatever is left out keeps its default.
+    pub fn params<P: DeserializeOwned>(&self, day: u8) -> Result<P> {
+        let mut section = match self.table.get(&format!("day{day}")) {
+            Some(toml::Value::Table(section)) => section.clone(),
+            Some(_) => bail!("[day{day}] in {} is not a table.", self.path.display()),
+            None => toml::Table::new(),
+        };
+        match section.remove("example") {
+            Some(toml::Value::Table(example)) if self.example => section.extend(example),
+            Some(toml::Value::Table(_)) | None => {}
+            Some(_) => bail!(
+                "[day{day}.example] in {} is not a table.",
+                self.path.display()
+            ),
+        }
+        for o in &self.overrides {
+            if o.day.is_none_or(|o_day| o_day == day) {
+                section.insert(o.key.clone(), o.value.clone());
+            }
+        }
+        toml::Value::Table(section)
+            .try_into()
+            .with_context(|| format!("Invalid parameters for day {day}"))
+    }
+}
+
+#[derive(Parser)]
+struct DayCli {
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    config: ConfigArgs,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+/// Like [`read_input`](crate::read_input), but also accepts the parameter options and
+/// returns the parameters of day `S` along with the input.
+pub fn read_input_and_params<S: Solution>(
+    day_dir: impl AsRef<Path>,
+) -> Result<(String, S::Params)> {
+    let cli = DayCli::parse();
+    cli.log.init();
+    let day_dir = day_dir.as_ref();
+    let workspace_dir = day_dir.parent().unwrap_or(day_dir);
+    let params = cli
+        .config
+        .load(workspace_dir, cli.input.example)?
+        .params(S::DAY)?;
+    Ok((cli.input.read(day_dir)?, params))
+}
+
+#[test]
+fn overrides_and_example_sections() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct Params {
+        row: i32,
+        name: String,
+    }
+    impl Default for Params {
+        fn default() -> Self {
+            Self {
+                row: 1,
+                name: "AA".to_owned(),
+            }
+        }
+    }
+
+    let table: toml::Table = "[day15]\nrow = 2000000\n[day15.example]\nrow = 10\n"
+        .parse()
+        .unwrap();
+    let mut config = Config {
+        table,
+        ..Default::default()
+    };
+    let params: Params = config.params(15).unwrap();
+    assert_eq!((params.row, params.name.as_str()), (2000000, "AA"));
+    config.example = true;
+    assert_eq!(config.params::<Params>(15).unwrap().row, 10);
+    assert_eq!(config.params::<Params>(14).unwrap(), Params::default());
+
+    config.overrides = vec![
+        "name=BB".parse().unwrap(),
+        "day15.row = -3".parse().unwrap(),
+    ];
+    let params: Params = config.params(15).unwrap();
+    assert_eq!((params.row, params.name.as_str()), (-3, "BB"));
+    assert_eq!(config.params::<Params>(16).unwrap().row, 1);
+
+    config.overrides = vec!["rows=3".parse().unwrap()];
+    assert!(config.params::<Params>(15).is_err());
+    assert!("day.row=3".parse::<Override>().is_err());
+}
@@ -0,0 +1,134 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::config::{Config, Override};
+use crate::solution::{Answer, Solution};
+
+/// File name of the example registry in the workspace root.
+pub const EXAMPLES_FILE: &str = "examples.toml";
+
+/// An example input from a puzzle description, with the answer one of its parts should
+/// have. In the registry, `dayN` is an array of these.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    /// Input file in the directory of the day, e.g. "input_test.txt".
+    pub file: String,
+    /// 1 or 2.
+    pub part: u8,
+    /// Expected answer, left out for parts that aren't solved (yet).
+    pub answer: Option<Answer>,
+    /// Puzzle parameters differing from the defaults of the day (`aoc.toml` doesn't
+    /// apply), e.g. `{ row = 10 }` for day 15.
+    #[serde(default)]
+    pub params: toml::Table,
+}
+
+/// Examples of `day` in the text of the registry, in the order they are listed.
+pub fn examples(registry: &str, day: u8) -> Result<Vec<Example>> {
+    let mut table: toml::Table = registry.parse()?;
+    match table.remove(&format!("day{day}")) {
+        Some(examples) => examples
+            .try_into()
+            .with_context(|| format!("Invalid examples for day {day}")),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Solve every example of day `S` in the registry, failing with a list of the examples
+/// that went wrong. `day_dir` is the directory of the day crate, the registry lives next
+/// to it in the workspace root. Use [`example_tests!`](crate::example_tests) rather than
+/// calling this directly.
+pub fn check_examples<S: Solution>(day_dir: impl AsRef<Path>) -> Result<()> {
+    let day_dir = day_dir.as_ref();
+    let path = day_dir.parent().unwrap_or(day_dir).join(EXAMPLES_FILE);
+    let registry =
+        fs::read_to_string(&path).with_context(|| format!("Couldn't read {}", path.display()))?;
+    let examples = examples(&registry, S::DAY)
+        .with_context(|| format!("Couldn't parse {}", path.display()))?;
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        let expected = example.answer.clone().unwrap_or(Answer::Unsolved);
+        match solve_example::<S>(day_dir, example) {
+            Ok(answer) if answer == expected => {}
+            Ok(answer) => failures.push(format!(
+                "{} part {}: expected {expected}, got {answer}",
+                example.file, example.part
+            )),
+            Err(err) => failures.push(format!("{} part {}: {err:#}", example.file, example.part)),
+        }
+    }
+    if !failures.is_empty() {
+        bail!(
+            "{} of {} example(s) of day {} failed:\n{}",
+            failures.len(),
+            examples.len(),
+            S::DAY,
+            failures.join("\n")
+        );
+    }
+    Ok(())
+}
+
+/// Solve the part of the example, and only that one.
+fn solve_example<S: Solution>(day_dir: &Path, example: &Example) -> Result<Answer> {
+    let path = day_dir.join(&example.file);
+    let input =
+        fs::read_to_string(&path).with_context(|| format!("Couldn't read {}", path.display()))?;
+    let overrides = example
+        .params
+        .iter()
+        .map(|(key, value)| Override {
+            day: None,
+            key: key.clone(),
+            value: value.clone(),
+        })
+        .collect();
+    let params = Config::with_overrides(overrides).params::<S::Params>(S::DAY)?;
+    let input = S::parse(&input)?;
+    match example.part {
+        1 => S::part1(&input, &params),
+        2 => S::part2(&input, &params),
+        part => bail!("There is no part {part}."),
+    }
+}
+
+/// Generate the `examples` test of a day crate, which solves all examples of the day
+/// listed in the example registry `examples.toml` in the workspace root:
+///
+/// ```ignore
+/// aoc_common::example_tests!(Day15);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($day:ty) => {
+        /// Solves the examples of the day listed in the example registry.
+        #[test]
+        fn examples() {
+            if let Err(err) = $crate::check_examples::<$day>(env!("CARGO_MANIFEST_DIR")) {
+                panic!("{err:#}");
+            }
+        }
+    };
+}
+
+#[test]
+fn examples_of_a_day() {
+    let registry = r#"
+        day15 = [
+            { file = "input_test.txt", part = 1, answer = 26, params = { row = 10 } },
+            { file = "input_test.txt", part = 2 },
+        ]
+    "#;
+    let day15 = examples(registry, 15).unwrap();
+    assert_eq!(day15.len(), 2);
+    assert_eq!(day15[0].answer, Some(Answer::Number(26)));
+    assert_eq!(day15[0].params["row"], toml::Value::Integer(10));
+    assert_eq!((day15[1].part, &day15[1].answer), (2, &None));
+    assert!(examples(registry, 14).unwrap().is_empty());
+    assert!(examples("day1 = [{ file = \"a\" }]", 1).is_err());
+}
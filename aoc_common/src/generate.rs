@@ -0,0 +1,22 @@
+use rand::SeedableRng;
+
+use crate::solution::Solution;
+
+/// Random number generator handed to the input generators. It is seeded, so the same
+/// seed always gives the same input.
+pub type InputRng = rand::rngs::StdRng;
+
+/// Generator of random puzzle inputs, for stress tests and benchmarks beyond the single
+/// puzzle input and the small examples.
+pub trait GenerateInput: Solution {
+    /// Structurally valid input (i.e. one the puzzle could have come with) whose size
+    /// grows with `size`. What `size` counts depends on the day, e.g. elves for day 1
+    /// or sensors for day 15. The parameters are passed in, since some inputs depend on
+    /// them, like the valve to start at on day 16.
+    fn generate_input(rng: &mut InputRng, size: usize, params: &Self::Params) -> String;
+}
+
+/// Generate an input for day `S` from the given seed.
+pub fn generate<S: GenerateInput>(seed: u64, size: usize, params: &S::Params) -> String {
+    S::generate_input(&mut InputRng::seed_from_u64(seed), size, params)
+}
@@ -0,0 +1,284 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::parse::{InputLine, ParseError};
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Offsets of all eight neighbours, clockwise starting from up.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Rectangular grid stored row by row in one flat vector. Positions are `(x, y)`
+/// tuples, with `x` counting columns from the left and `y` counting rows from the top.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parse a grid with one row per line and one cell per char, converting each
+    /// char with `parse_cell`. Chars it rejects are reported as not being `expected`.
+    /// All lines need to have the same length.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in InputLine::numbered(input.lines()) {
+            let line_width = line.text.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(line.error(format!("a row of {width} cells")))
+                }
+                Some(_) => {}
+            }
+            for (idx, ch) in line.text.char_indices() {
+                let cell = parse_cell(ch)
+                    .ok_or_else(|| line.error_at(&line.text[idx..idx + ch.len_utf8()], expected))?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the (signed) position lies on the grid.
+    pub fn contains(&self, (x, y): (isize, isize)) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// Bounds-checked access, `None` for positions outside of the grid.
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// Bounds-checked mutable access, `None` for positions outside of the grid.
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells together with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell (row by row) matching the predicate.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Cells of row `y`, from left to right.
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            y < self.height,
+            "Row {y} is outside of the {}x{} grid.",
+            self.width,
+            self.height
+        );
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// Cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "Column {x} is outside of the {}x{} grid.",
+            self.width,
+            self.height
+        );
+        self.cells[x..]
+            .iter()
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    /// Cells seen when walking from `start` in steps of `(dx, dy)` until leaving
+    /// the grid. The start cell itself is not included.
+    pub fn ray(
+        &self,
+        start: (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> impl Iterator<Item = &T> + '_ {
+        let mut pos = (start.0 as isize, start.1 as isize);
+        std::iter::from_fn(move || {
+            pos = (pos.0 + dx, pos.1 + dy);
+            let in_grid = (dx, dy) != (0, 0) && self.contains(pos);
+            in_grid.then(|| &self[(pos.0 as usize, pos.1 as usize)])
+        })
+    }
+
+    /// Positions of the up to four orthogonal neighbours inside the grid.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &DIRECTIONS_4)
+    }
+
+    /// Positions of the up to eight neighbours (including diagonals) inside the grid.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &DIRECTIONS_8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        directions: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        directions.iter().filter_map(move |&(dx, dy)| {
+            let pos = (x as isize + dx, y as isize + dy);
+            self.contains(pos)
+                .then_some((pos.0 as usize, pos.1 as usize))
+        })
+    }
+
+    /// New grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "Position {pos:?} is outside of the {}x{} grid.",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is outside of the {width}x{height} grid."))
+    }
+}
+
+/// Plain char grid, as found in most puzzle inputs.
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, "any char", Some)
+    }
+}
+
+/// One line per row, cells written next to each other without separator.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn parse_and_display_round_trip() {
+    let input = "abc\ndef";
+    let grid: Grid<char> = input.parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 0)], 'c');
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.to_string(), input);
+    assert_eq!("ab\nc".parse::<Grid<char>>().unwrap_err().line, 2);
+    let err = Grid::parse("12\n3x", "a digit", |ch| ch.to_digit(10)).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+}
+
+#[test]
+fn rows_columns_and_rays() {
+    let grid = Grid::parse("123\n456\n789", "a digit", |ch| ch.to_digit(10)).unwrap();
+    assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+    assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), [9, 6, 3]);
+    assert_eq!(
+        grid.ray((0, 0), (1, 1)).copied().collect::<Vec<_>>(),
+        [5, 9]
+    );
+    assert_eq!(grid.ray((1, 1), (-1, 0)).copied().collect::<Vec<_>>(), [4]);
+    assert_eq!(grid.position(|&cell| cell == 8), Some((1, 2)));
+}
+
+#[test]
+#[should_panic(expected = "Column 3 is outside of the 3x2 grid.")]
+fn column_outside() {
+    let grid = Grid::new(3, 2, 0);
+    let _ = grid.column(3);
+}
+
+#[test]
+#[should_panic(expected = "Row 2 is outside of the 3x2 grid.")]
+fn row_outside() {
+    let grid = Grid::new(3, 2, 0);
+    let _ = grid.row(2);
+}
+
+#[test]
+fn neighbours_stay_inside() {
+    let grid = Grid::new(3, 2, 0);
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+}
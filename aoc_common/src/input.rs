@@ -0,0 +1,127 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Args, Parser};
+use sha2::{Digest, Sha256};
+
+use crate::lines::LineReader;
+use crate::logging::LogArgs;
+
+/// File name of the personal puzzle input inside a day directory. It is not checked in.
+pub const INPUT_FILE: &str = "input.txt";
+/// File name of the example input from the puzzle description inside a day directory.
+pub const EXAMPLE_FILE: &str = "input_test.txt";
+
+/// Command line options selecting the puzzle input, shared by the day binaries and the runner.
+#[derive(Args, Debug, Clone, Default)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file instead of input.txt; "-" reads from stdin.
+    #[arg(long, value_name = "PATH", conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+    /// Use the example input from the puzzle description (input_test.txt).
+    #[arg(long)]
+    pub example: bool,
+}
+
+impl InputArgs {
+    /// Whether the input comes from a user-given file or stdin rather than from the day directory.
+    pub fn is_explicit(&self) -> bool {
+        self.input.is_some()
+    }
+
+    /// Read the selected puzzle input for the day living in `day_dir`.
+    pub fn read(&self, day_dir: impl AsRef<Path>) -> Result<String> {
+        match self.file(day_dir.as_ref()) {
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Couldn't read the puzzle input from stdin")?;
+                Ok(input)
+            }
+            Some((path, hint)) => read_file(&path, hint),
+        }
+    }
+
+    /// Open the selected puzzle input for the day living in `day_dir`, to be read lazily
+    /// line by line instead of all at once like with [`read`](Self::read).
+    pub fn open(&self, day_dir: impl AsRef<Path>) -> Result<LineReader<Box<dyn BufRead>>> {
+        let reader: Box<dyn BufRead> = match self.file(day_dir.as_ref()) {
+            None => Box::new(io::stdin().lock()),
+            Some((path, hint)) => match File::open(&path) {
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    anyhow::bail!("Puzzle input {} not found. {hint}", path.display())
+                }
+                result => Box::new(BufReader::new(
+                    result.with_context(|| format!("Couldn't open {}", path.display()))?,
+                )),
+            },
+        };
+        Ok(LineReader::new(reader))
+    }
+
+    /// File of the selected puzzle input, with a hint in case it doesn't exist, or `None`
+    /// for stdin.
+    fn file(&self, day_dir: &Path) -> Option<(PathBuf, &'static str)> {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => None,
+            Some(path) => Some((path.clone(), "Pass an existing file to --input.")),
+            None if self.example => {
+                Some((day_dir.join(EXAMPLE_FILE), "This day has no example input."))
+            }
+            None => Some((
+                day_dir.join(INPUT_FILE),
+                "Save your puzzle input there, pass --input <PATH>, or use --example.",
+            )),
+        }
+    }
+}
+
+/// SHA-256 of the puzzle input as hex string. Identifies an input without storing
+/// (or publishing) the input itself.
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+fn read_file(path: &Path, hint: &str) -> Result<String> {
+    match fs::read_to_string(path) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            anyhow::bail!("Puzzle input {} not found. {hint}", path.display())
+        }
+        result => result.with_context(|| format!("Couldn't read {}", path.display())),
+    }
+}
+
+#[derive(Parser)]
+struct DayCli {
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+/// Parse the command line of a day binary, set up diagnostics and read the puzzle input
+/// it selects. `day_dir` is the directory of the day crate, i.e. `env!("CARGO_MANIFEST_DIR")`.
+pub fn read_input(day_dir: impl AsRef<Path>) -> Result<String> {
+    let cli = DayCli::parse();
+    cli.log.init();
+    cli.input.read(day_dir)
+}
+
+/// Like [`read_input`], but open the puzzle input to be read lazily line by line, for
+/// days that process inputs of any size in constant memory.
+pub fn open_input(day_dir: impl AsRef<Path>) -> Result<LineReader<Box<dyn BufRead>>> {
+    let cli = DayCli::parse();
+    cli.log.init();
+    cli.input.open(day_dir)
+}
+
+#[test]
+fn input_hash_is_sha256() {
+    assert_eq!(
+        input_hash("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
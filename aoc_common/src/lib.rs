@@ -0,0 +1,39 @@
+//! Shared building blocks for the Advent of Code 2022 solutions.
+//!
+//! Every day crate implements the [`Solution`] trait, which is what allows the
+//! `aoc` runner to drive all of them the same way. [`GenerateInput`] adds random
+//! inputs of any size on top, and [`Oracle`] alternative implementations to check the
+//! solutions against. Days implementing [`Visualize`] can be rendered to images, or
+//! in the terminal with the [`TerminalRenderer`]. The day parsers are built from the
+//! [`combinators`], which point at the exact spot of the input that's wrong. Days that
+//! don't need their whole input at once stream it with a [`LineReader`]. Every day is
+//! tested against the examples of the puzzle descriptions listed in `examples.toml`,
+//! with tests generated by [`example_tests!`].
+pub mod combinators;
+mod config;
+mod examples;
+mod generate;
+mod grid;
+mod input;
+mod lines;
+mod logging;
+mod oracle;
+mod parse;
+mod render;
+mod solution;
+mod terminal;
+mod vec2;
+
+pub use config::{read_input_and_params, Config, ConfigArgs, NoParams, Override, CONFIG_FILE};
+pub use examples::{check_examples, examples, Example, EXAMPLES_FILE};
+pub use generate::{generate, GenerateInput, InputRng};
+pub use grid::{Grid, DIRECTIONS_4, DIRECTIONS_8};
+pub use input::{input_hash, open_input, read_input, InputArgs, EXAMPLE_FILE, INPUT_FILE};
+pub use lines::{Group, LineReader};
+pub use logging::LogArgs;
+pub use oracle::{consult, Oracle};
+pub use parse::{InputLine, ParseError};
+pub use render::{frame_stride, render, show, write_frames, Frame, Rgb, Visualize, MAX_FRAMES};
+pub use solution::{solve, Answer, DayResult, PartResult, Solution};
+pub use terminal::{Cell, TerminalRenderer};
+pub use vec2::{Coordinate, Vec2};
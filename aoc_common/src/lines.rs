@@ -0,0 +1,123 @@
+use std::io::{self, BufRead};
+
+use crate::parse::InputLine;
+
+/// Reads puzzle input lazily, one line at a time into a buffer that is reused, so inputs
+/// of any size are processed in constant memory (as long as their lines are short). Lines
+/// are lent out as [`InputLine`]s without their line ending, `"\n"` or `"\r\n"` (or a
+/// lone `"\r"` at the very end), and numbered for error messages. A lent line lives until
+/// the next one is read, which is why this is no [`Iterator`]: loop with
+/// `while let Some(line) = lines.next_line()?`.
+///
+/// Many puzzle inputs come in records of several lines separated by blank lines, these
+/// are read with [`next_group`](Self::next_group).
+pub struct LineReader<R> {
+    reader: R,
+    buffer: String,
+    nr: usize,
+    /// The buffer holds a line that was looked at, but not handed out yet.
+    pending: bool,
+    /// A group was handed out, and its lines weren't read to the end.
+    in_group: bool,
+}
+
+impl<R: BufRead> LineReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            nr: 0,
+            pending: false,
+            in_group: false,
+        }
+    }
+
+    /// The next line, or `None` at the end of the input.
+    pub fn next_line(&mut self) -> io::Result<Option<InputLine<'_>>> {
+        self.in_group = false;
+        Ok(self.advance()?.then(|| self.current()))
+    }
+
+    /// The next group of lines, up to a blank line or the end of the input, or `None` if
+    /// there are no more. Blank lines before the group are skipped, so several blank lines
+    /// in a row separate groups just like one, and there are no empty groups. Lines the
+    /// previous group didn't read are skipped too.
+    pub fn next_group(&mut self) -> io::Result<Option<Group<'_, R>>> {
+        while self.in_group {
+            self.in_group = self.advance()? && !self.buffer.is_empty();
+        }
+        loop {
+            if !self.advance()? {
+                return Ok(None);
+            }
+            if !self.buffer.is_empty() {
+                self.pending = true;
+                self.in_group = true;
+                return Ok(Some(Group { lines: self }));
+            }
+        }
+    }
+
+    /// Move on to the next line, returning whether there is one.
+    fn advance(&mut self) -> io::Result<bool> {
+        if self.pending {
+            self.pending = false;
+            return Ok(true);
+        }
+        self.buffer.clear();
+        if self.reader.read_line(&mut self.buffer)? == 0 {
+            return Ok(false);
+        }
+        self.nr += 1;
+        if self.buffer.ends_with('\n') {
+            self.buffer.pop();
+        }
+        // also when the last line of a file with CRLF endings lacks the LF
+        if self.buffer.ends_with('\r') {
+            self.buffer.pop();
+        }
+        Ok(true)
+    }
+
+    fn current(&self) -> InputLine<'_> {
+        InputLine::new(self.nr, &self.buffer)
+    }
+}
+
+/// Lines of a group of a [`LineReader`], see [`LineReader::next_group`].
+pub struct Group<'r, R> {
+    lines: &'r mut LineReader<R>,
+}
+
+impl<R: BufRead> Group<'_, R> {
+    /// The next line of the group, or `None` at its end.
+    pub fn next_line(&mut self) -> io::Result<Option<InputLine<'_>>> {
+        if !self.lines.in_group {
+            return Ok(None);
+        }
+        self.lines.in_group = self.lines.advance()? && !self.lines.buffer.is_empty();
+        Ok(self.lines.in_group.then(|| self.lines.current()))
+    }
+}
+
+#[test]
+fn lines_and_groups() {
+    let mut lines = LineReader::new("a\r\nb\n\n\n\nc\nd\ne\n\nf\r".as_bytes());
+    let line = lines.next_line().unwrap().unwrap();
+    assert_eq!((line.nr, line.text), (1, "a"));
+
+    let mut groups = Vec::new();
+    while let Some(mut group) = lines.next_group().unwrap() {
+        let mut texts = Vec::new();
+        while let Some(line) = group.next_line().unwrap() {
+            texts.push(format!("{}:{}", line.nr, line.text));
+            // the rest of the group with "c" is skipped
+            if line.text == "c" {
+                break;
+            }
+        }
+        groups.push(texts.join(" "));
+    }
+    assert_eq!(groups, ["2:b", "6:c", "10:f"]);
+    assert!(lines.next_line().unwrap().is_none());
+}
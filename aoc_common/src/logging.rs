@@ -0,0 +1,57 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+use clap::{ArgAction, Args};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+/// Command line options for how much diagnostic output goes to stderr, shared by the day
+/// binaries and the runner. Answers go to stdout and are never affected.
+#[derive(Args, Debug, Clone, Default)]
+pub struct LogArgs {
+    /// Show more diagnostics on stderr: -v for details like timings and intermediate
+    /// values, -vv for everything.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+    /// Show fewer diagnostics on stderr: -q for errors only, -qq for none.
+    #[arg(short, long, action = ArgAction::Count, global = true, conflicts_with = "verbose")]
+    pub quiet: u8,
+}
+
+impl LogArgs {
+    /// Most detailed level shown, notes like the seed of a generated input by default.
+    pub fn level(&self) -> LevelFilter {
+        match (self.verbose, self.quiet) {
+            (0, 0) => LevelFilter::INFO,
+            (1, _) => LevelFilter::DEBUG,
+            (_, 0) => LevelFilter::TRACE,
+            (_, 1) => LevelFilter::ERROR,
+            _ => LevelFilter::OFF,
+        }
+    }
+
+    /// Send diagnostics to stderr, at the level of the options, coloured on a terminal
+    /// unless `NO_COLOR` is set. `RUST_LOG` takes precedence if set, e.g.
+    /// `RUST_LOG=p15=trace` for everything of day 15 and nothing else. Does nothing if
+    /// diagnostics are already set up.
+    pub fn init(&self) {
+        let filter = EnvFilter::builder()
+            .with_default_directive(self.level().into())
+            .from_env_lossy();
+        let _already_set_up = tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none())
+            .without_time()
+            .try_init();
+    }
+}
+
+#[test]
+fn flags_pick_level() {
+    let level = |verbose, quiet| LogArgs { verbose, quiet }.level();
+    assert_eq!(level(0, 0), LevelFilter::INFO);
+    assert_eq!(level(2, 0), LevelFilter::TRACE);
+    assert_eq!(level(0, 1), LevelFilter::ERROR);
+    assert_eq!(level(0, 3), LevelFilter::OFF);
+}
@@ -0,0 +1,17 @@
+use anyhow::Result;
+
+use crate::solution::{Answer, Solution};
+
+/// A second, independent implementation of a day (usually a slow or naive one) that the
+/// actual solution can be checked against, e.g. on generated inputs.
+pub trait Oracle: Solution {
+    /// Answers to both parts according to the alternative implementation. Parts it
+    /// doesn't cover, or can't decide for the given input, are [`Answer::Unsolved`] and
+    /// don't take part in the comparison.
+    fn oracle(input: &Self::Input<'_>, params: &Self::Params) -> Result<[Answer; 2]>;
+}
+
+/// Parse the raw input and ask the oracle of day `S`.
+pub fn consult<S: Oracle>(input: &str, params: &S::Params) -> Result<[Answer; 2]> {
+    S::oracle(&S::parse(input)?, params)
+}
@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input. Points at the offending spot of the input, and renders
+/// as the offending line with the spot underlined by carets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column in chars, starting at 1.
+    pub column: usize,
+    /// What the parser was looking for, e.g. "a number".
+    pub expected: String,
+    /// What was found instead. Empty at the end of the line.
+    pub found: String,
+    /// The offending input line, for the snippet.
+    pub source_line: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+        source_line: &str,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+            source_line: source_line.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let found = if self.found.is_empty() {
+            "end of line".to_owned()
+        } else {
+            format!("{:?}", self.found)
+        };
+        writeln!(
+            f,
+            "line {}, column {}: expected {}, found {found}",
+            self.line, self.column, self.expected
+        )?;
+        let gutter = " ".repeat(self.line.to_string().len());
+        let carets = "^".repeat(self.found.chars().count().max(1));
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{gutter} | {}{carets}", " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of puzzle input together with its line number, for parsers that want to
+/// report where things went wrong.
+#[derive(Debug, Clone, Copy)]
+pub struct InputLine<'a> {
+    /// Line number, starting at 1.
+    pub nr: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn new(nr: usize, text: &'a str) -> Self {
+        Self { nr, text }
+    }
+
+    /// Number the given lines, starting at 1.
+    pub fn numbered(lines: impl IntoIterator<Item = &'a str>) -> impl Iterator<Item = Self> {
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(idx, text)| Self::new(idx + 1, text))
+    }
+
+    /// Error pointing at `token`, which has to be a slice of this line. An empty
+    /// slice at the end of the line means the line ended too early.
+    pub fn error_at(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset <= self.text.len())
+            .unwrap_or(0);
+        let column = self.text[..offset].chars().count() + 1;
+        ParseError::new(self.nr, column, expected, token, self.text)
+    }
+
+    /// Error pointing at the whole line.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.text, expected)
+    }
+
+    /// Parse `token` (a slice of this line), pointing at it if that fails.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error_at(token, expected))
+    }
+
+    /// Split `part` (a slice of this line) at the first `delimiter`.
+    pub fn split_once(
+        &self,
+        part: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error_at(&part[part.len()..], format!("{delimiter:?}")))
+    }
+
+    /// Strip `prefix` from `part` (a slice of this line).
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        part.strip_prefix(prefix).ok_or_else(|| {
+            let mismatch = part.len().min(prefix.len());
+            let found = part.get(..mismatch).unwrap_or(part);
+            self.error_at(found, format!("{prefix:?}"))
+        })
+    }
+}
+
+#[test]
+fn error_points_at_token() {
+    let line = InputLine::new(12, "2-x,6-8");
+    let err = line
+        .parse::<u32>(&line.text[2..3], "a section number")
+        .unwrap_err();
+    assert_eq!((err.line, err.column), (12, 3));
+    assert_eq!(
+        err.to_string(),
+        "line 12, column 3: expected a section number, found \"x\"\n   |\n12 | 2-x,6-8\n   |   ^"
+    );
+}
+
+#[test]
+fn missing_delimiter_points_at_end_of_line() {
+    let line = InputLine::new(1, "noop 3");
+    let err = line.split_once(line.text, ",").unwrap_err();
+    assert_eq!((err.column, err.found.as_str()), (7, ""));
+    assert!(err.to_string().contains("found end of line"));
+    let err = line.strip_prefix(line.text, "addx ").unwrap_err();
+    assert_eq!((err.column, err.found.as_str()), (1, "noop "));
+}
@@ -0,0 +1,159 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use anyhow::{bail, ensure, Context, Result};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, DynamicImage, RgbImage};
+
+use crate::grid::Grid;
+use crate::solution::Solution;
+use crate::terminal::{Cell, TerminalRenderer};
+
+/// Colour of a cell, as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// One picture of a visualisation, one colour per cell. The backends scale it up, so a
+/// cell of the puzzle can be a cell of the frame.
+pub type Frame = Grid<Rgb>;
+
+/// Number of frames a visualisation should stay within, so animations stay small.
+pub const MAX_FRAMES: usize = 250;
+
+/// Images are scaled up until their longer side has at least this many pixels.
+const MIN_IMAGE_SIZE: usize = 400;
+
+/// Time each frame of an animation is shown.
+const FRAME_DELAY_MS: u32 = 40;
+
+/// Visualisation of how a day is solved, as a sequence of frames.
+pub trait Visualize: Solution {
+    /// Frames of the visualisation, in order and all of the same size. Long simulations
+    /// only show every n-th step (see [`frame_stride`]); the last frame shows the end.
+    fn frames(input: &Self::Input<'_>, params: &Self::Params) -> Result<Vec<Frame>>;
+
+    /// Character standing for a colour of the frames in the terminal, where cells are
+    /// coloured as well if possible. A full block by default.
+    fn glyph(_colour: Rgb) -> char {
+        '█'
+    }
+}
+
+/// Every how many steps of a simulation with `nr_steps` steps to take a frame, so
+/// there are at most [`MAX_FRAMES`] of them.
+pub fn frame_stride(nr_steps: usize) -> usize {
+    nr_steps.div_ceil(MAX_FRAMES).max(1)
+}
+
+/// Parse the raw input, visualise day `S` and write the frames to `path`.
+pub fn render<S: Visualize>(input: &str, params: &S::Params, path: &Path) -> Result<()> {
+    write_frames(&S::frames(&S::parse(input)?, params)?, path)
+}
+
+/// Parse the raw input, visualise day `S` and draw it in the terminal: all frames in
+/// the same place with `animate`, otherwise only the last one.
+pub fn show<S: Visualize>(
+    input: &str,
+    params: &S::Params,
+    renderer: &TerminalRenderer,
+    animate: bool,
+) -> Result<()> {
+    let frames = S::frames(&S::parse(input)?, params)?;
+    let cell = |&colour: &Rgb| Cell {
+        glyph: S::glyph(colour),
+        colour: Some(colour),
+    };
+    let mut out = io::stdout().lock();
+    match frames.split_last() {
+        None => bail!("Nothing to show."),
+        Some((last, _)) if !animate => renderer.draw(&mut out, last, cell)?,
+        Some(_) => renderer.animate(&mut out, &frames, cell)?,
+    }
+    Ok(())
+}
+
+/// Write frames to `path`, in the format its extension asks for: the last frame as a
+/// still image for ".png" and ".svg", all of them as an animation for ".gif".
+pub fn write_frames(frames: &[Frame], path: &Path) -> Result<()> {
+    let Some(last) = frames.last() else {
+        bail!("Nothing to render.");
+    };
+    ensure!(
+        frames
+            .iter()
+            .all(|frame| (frame.width(), frame.height()) == (last.width(), last.height())),
+        "All frames need to have the same size."
+    );
+    let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+    let written = match extension.to_ascii_lowercase().as_str() {
+        "png" => to_image(last).save(path).map_err(anyhow::Error::from),
+        "svg" => fs::write(path, to_svg(last)).map_err(anyhow::Error::from),
+        "gif" => write_gif(frames, path),
+        _ => bail!(
+            "Can't render to {}, use .png, .svg or .gif.",
+            path.display()
+        ),
+    };
+    written.with_context(|| format!("Couldn't write {}", path.display()))
+}
+
+/// How many pixels wide and high a cell of the frame is drawn.
+fn scale(frame: &Frame) -> usize {
+    (MIN_IMAGE_SIZE / frame.width().max(frame.height()).max(1)).max(1)
+}
+
+fn to_image(frame: &Frame) -> RgbImage {
+    let scale = scale(frame);
+    RgbImage::from_fn(
+        (frame.width() * scale) as u32,
+        (frame.height() * scale) as u32,
+        |x, y| image::Rgb(frame[(x as usize / scale, y as usize / scale)]),
+    )
+}
+
+/// SVG with one rectangle per run of same-coloured cells in a row.
+fn to_svg(frame: &Frame) -> String {
+    let scale = scale(frame);
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+        frame.width(),
+        frame.height()
+    );
+    for y in 0..frame.height() {
+        let row = frame.row(y).collect::<Vec<_>>();
+        let mut x = 0;
+        for run in row.chunk_by(|a, b| a == b) {
+            let [r, g, b] = *run[0];
+            svg += &format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>\n",
+                run.len()
+            );
+            x += run.len();
+        }
+    }
+    svg + "</svg>\n"
+}
+
+fn write_gif(frames: &[Frame], path: &Path) -> Result<()> {
+    let mut encoder = GifEncoder::new_with_speed(BufWriter::new(File::create(path)?), 10);
+    encoder.set_repeat(Repeat::Infinite)?;
+    for frame in frames {
+        let image = DynamicImage::ImageRgb8(to_image(frame)).into_rgba8();
+        let delay = Delay::from_numer_denom_ms(FRAME_DELAY_MS, 1);
+        encoder.encode_frame(image::Frame::from_parts(image, 0, 0, delay))?;
+    }
+    Ok(())
+}
+
+#[test]
+fn svg_merges_runs() {
+    let mut frame = Frame::new(3, 1, [0, 0, 0]);
+    frame[(2, 0)] = [255, 16, 0];
+    let svg = to_svg(&frame);
+    assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#000000\"/>"));
+    assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ff1000\"/>"));
+    assert_eq!(frame_stride(MAX_FRAMES + 1), 2);
+    assert!(write_frames(&[frame], Path::new("frame.bmp")).is_err());
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tracing::{debug, info_span};
+
+/// Answer to one part of a puzzle. Most answers are plain numbers, but some days
+/// ask for strings (e.g. the crate letters of day 5).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Part not solved (yet).
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Unsolved => write!(f, "(unsolved)"),
+        }
+    }
+}
+
+/// Numbers stay numbers, text becomes a string and unsolved parts become `null`.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(number) => serializer.serialize_i64(*number),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Counterpart of the `Serialize` impl, for reading recorded answers back in.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Recorded {
+            Number(i64),
+            Text(String),
+            Unsolved(()),
+        }
+        Ok(match Recorded::deserialize(deserializer)? {
+            Recorded::Number(number) => Answer::Number(number),
+            Recorded::Text(text) => Answer::Text(text),
+            Recorded::Unsolved(()) => Answer::Unsolved,
+        })
+    }
+}
+
+/// Lossless conversion from the integer types used by the solvers.
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    // Values not fitting an i64 don't appear in practice, but rather
+                    // keep their digits than wrap around.
+                    i64::try_from(value)
+                        .map_or_else(|_| Answer::Text(value.to_string()), Answer::Number)
+                }
+            }
+        )*
+    };
+}
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// A puzzle solution: parse the input once, then answer both parts from the parsed form.
+pub trait Solution {
+    /// Day of the puzzle in the advent calendar.
+    const DAY: u8;
+
+    /// Puzzle parameters like the number of rounds to simulate, read from the `[dayN]`
+    /// section of aoc.toml. Days without any use [`NoParams`](crate::NoParams).
+    type Params: DeserializeOwned;
+
+    /// Parsed puzzle input shared by both parts. May borrow from the raw input.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Answer>;
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Answer>;
+}
+
+/// Answer to one part together with the time it took to compute it (without parsing).
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Outcome of solving both parts of a day.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    /// Time it took to parse the input.
+    pub parse_time: Duration,
+    pub parts: [PartResult; 2],
+}
+
+/// Parse the raw input and solve both parts of the puzzle, timing each step. Diagnostics
+/// of the day are logged within a "day" span, those of each part in a "part" span.
+pub fn solve<S: Solution>(input: &str, params: &S::Params) -> Result<DayResult> {
+    let _day = info_span!("day", day = S::DAY).entered();
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+    debug!(?parse_time, "parsed");
+    let part1 = timed(1, || S::part1(&parsed, params))?;
+    let part2 = timed(2, || S::part2(&parsed, params))?;
+    Ok(DayResult {
+        parse_time,
+        parts: [part1, part2],
+    })
+}
+
+fn timed(part: u8, solve_part: impl FnOnce() -> Result<Answer>) -> Result<PartResult> {
+    let _part = info_span!("part", part).entered();
+    let start = Instant::now();
+    let answer = solve_part()?;
+    let elapsed = start.elapsed();
+    debug!(%answer, ?elapsed, "solved");
+    Ok(PartResult { answer, elapsed })
+}
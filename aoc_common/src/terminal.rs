@@ -0,0 +1,142 @@
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::grid::Grid;
+use crate::render::Rgb;
+
+/// How a cell of a grid is drawn in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    /// Character standing for the cell, e.g. '#' for rock.
+    pub glyph: char,
+    /// Colour of the glyph, or the default colour of the terminal.
+    pub colour: Option<Rgb>,
+}
+
+/// Draws grids as text, one glyph per cell, coloured with ANSI escape codes.
+#[derive(Debug, Clone)]
+pub struct TerminalRenderer {
+    /// Whether to colour the glyphs. Without colours, only the glyphs tell cells apart.
+    pub colour: bool,
+    /// Time each frame of an animation is shown.
+    pub frame_delay: Duration,
+}
+
+/// Colours if stdout is a terminal and `NO_COLOR` isn't set, and 25 frames per second.
+impl Default for TerminalRenderer {
+    fn default() -> Self {
+        Self {
+            colour: io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+            frame_delay: Duration::from_millis(40),
+        }
+    }
+}
+
+impl TerminalRenderer {
+    /// Draw a grid, one line per row.
+    pub fn draw<T>(
+        &self,
+        out: &mut impl Write,
+        grid: &Grid<T>,
+        cell: impl Fn(&T) -> Cell,
+    ) -> io::Result<()> {
+        let mut text = String::new();
+        for y in 0..grid.height() {
+            let mut current_colour = None;
+            for Cell { glyph, colour } in grid.row(y).map(&cell) {
+                if self.colour && colour != current_colour {
+                    text += &match colour {
+                        Some([r, g, b]) => format!("\x1b[38;2;{r};{g};{b}m"),
+                        None => "\x1b[39m".to_owned(),
+                    };
+                    current_colour = colour;
+                }
+                text.push(glyph);
+            }
+            if self.colour && current_colour.is_some() {
+                text += "\x1b[39m";
+            }
+            text.push('\n');
+        }
+        out.write_all(text.as_bytes())
+    }
+
+    /// Draw the grids one after the other in the same place, like a film. All of them
+    /// need to be of the same height, and the last one stays on screen.
+    ///
+    /// Without colours, or if stdout isn't a terminal, there are no escape codes to move
+    /// the cursor either: the grids are just drawn below each other, without delay if
+    /// stdout isn't a terminal.
+    pub fn animate<T>(
+        &self,
+        out: &mut impl Write,
+        grids: &[Grid<T>],
+        cell: impl Fn(&T) -> Cell,
+    ) -> io::Result<()> {
+        let terminal = io::stdout().is_terminal();
+        let in_place = self.colour && terminal;
+        if in_place {
+            write!(out, "\x1b[?25l")?; // hide the cursor while drawing
+        }
+        for (idx, grid) in grids.iter().enumerate() {
+            if idx > 0 {
+                if terminal {
+                    thread::sleep(self.frame_delay);
+                }
+                if in_place {
+                    write!(out, "\x1b[{}A", grid.height())?; // back up to the first row
+                }
+            }
+            self.draw(out, grid, &cell)?;
+            out.flush()?;
+        }
+        if in_place {
+            write!(out, "\x1b[?25h")?;
+        }
+        out.flush()
+    }
+}
+
+#[test]
+fn draw_with_and_without_colour() {
+    let grid: Grid<char> = "#.\n.#".parse().unwrap();
+    let cell = |&ch: &char| Cell {
+        glyph: ch,
+        colour: (ch == '#').then_some([255, 0, 0]),
+    };
+    let mut renderer = TerminalRenderer {
+        colour: false,
+        frame_delay: Duration::ZERO,
+    };
+    let mut out = Vec::new();
+    renderer.draw(&mut out, &grid, cell).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "#.\n.#\n");
+
+    renderer.colour = true;
+    let mut out = Vec::new();
+    renderer.draw(&mut out, &grid, cell).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "\x1b[38;2;255;0;0m#\x1b[39m.\n.\x1b[38;2;255;0;0m#\x1b[39m\n"
+    );
+}
+
+/// Without colours there are no escape codes at all, the frames simply follow each other.
+#[test]
+fn animate_without_colour() {
+    let grids = ["#.\n.#", "..\n##"].map(|grid| grid.parse::<Grid<char>>().unwrap());
+    let renderer = TerminalRenderer {
+        colour: false,
+        frame_delay: Duration::ZERO,
+    };
+    let mut out = Vec::new();
+    renderer
+        .animate(&mut out, &grids, |&ch| Cell {
+            glyph: ch,
+            colour: None,
+        })
+        .unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "#.\n.#\n..\n##\n");
+}
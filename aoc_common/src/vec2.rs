@@ -0,0 +1,166 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Signed integer types usable as `Vec2` coordinates.
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const NEG_ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($int:ty),*) => {
+        $(
+            impl Coordinate for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const NEG_ONE: Self = -1;
+
+                fn abs(self) -> Self {
+                    <$int>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$int>::signum(self)
+                }
+            }
+        )*
+    };
+}
+impl_coordinate!(i8, i16, i32, i64, isize);
+
+/// 2D point or vector. Like `Grid`, `x` grows to the right and `y` grows downwards,
+/// so `UP` is `(0, -1)` and rotating clockwise turns `UP` into `RIGHT`.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Vec2<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+    pub const UP: Self = Self::new(T::ZERO, T::NEG_ONE);
+    pub const DOWN: Self = Self::new(T::ZERO, T::ONE);
+    pub const LEFT: Self = Self::new(T::NEG_ONE, T::ZERO);
+    pub const RIGHT: Self = Self::new(T::ONE, T::ZERO);
+
+    /// Manhattan (taxicab) distance: steps needed when moving only horizontally or vertically.
+    pub fn manhattan(self, other: Self) -> T {
+        let diff = self - other;
+        diff.x.abs() + diff.y.abs()
+    }
+
+    /// Chebyshev distance: steps needed when diagonal moves are allowed as well.
+    pub fn chebyshev(self, other: Self) -> T {
+        let diff = self - other;
+        diff.x.abs().max(diff.y.abs())
+    }
+
+    /// Sign of both components, i.e. the step of at most one unit along each axis
+    /// that points in the direction of the vector.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotate by 90° clockwise (on screen, with `y` pointing down).
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotate by 90° counterclockwise (on screen, with `y` pointing down).
+    pub fn rotate_ccw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Scaling by a scalar.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[test]
+fn arithmetic_and_distances() {
+    let a = Vec2::new(1, -2);
+    let b = Vec2::new(-3, 4);
+    assert_eq!(a + b, Vec2::new(-2, 2));
+    assert_eq!(a - b, Vec2::new(4, -6));
+    assert_eq!(-a, Vec2::new(-1, 2));
+    assert_eq!(a * 3, Vec2::new(3, -6));
+    assert_eq!(a.manhattan(b), 10);
+    assert_eq!(a.chebyshev(b), 6);
+    assert_eq!((a - b).signum(), Vec2::new(1, -1));
+}
+
+#[test]
+fn rotation_and_directions() {
+    assert_eq!(Vec2::<i32>::UP.rotate_cw(), Vec2::RIGHT);
+    assert_eq!(Vec2::<i32>::RIGHT.rotate_cw(), Vec2::DOWN);
+    assert_eq!(Vec2::<i32>::DOWN.rotate_ccw(), Vec2::RIGHT);
+    assert_eq!(Vec2::<i64>::LEFT + Vec2::RIGHT, Vec2::ZERO);
+    assert_eq!(-Vec2::<i8>::UP, Vec2::DOWN);
+}
@@ -0,0 +1,198 @@
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::iter;
+
+use anyhow::Result;
+use aoc_common::LineReader;
+use serde::Serialize;
+
+use crate::read_elf;
+
+/// Percentiles of the [`Summary`] of a quantity, besides the median.
+pub const PERCENTILES: [u8; 4] = [10, 25, 75, 90];
+
+/// Everything an elf carries, rather than just the total of part 1 and 2.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ElfInventory {
+    /// Position of the elf in the input, starting at 0.
+    pub idx: usize,
+    /// Hp of each food item, in input order.
+    pub items: Vec<u64>,
+    /// Number of items.
+    pub count: usize,
+    /// Total hp of all items.
+    pub total: u64,
+}
+
+/// Read the inventory of each elf, lazily, one elf at a time. The input is taken just as
+/// strictly as for the answers, see [`elf_totals`](crate::elf_totals).
+pub fn elf_inventories<R: BufRead>(
+    mut lines: LineReader<R>,
+) -> impl Iterator<Item = Result<ElfInventory>> {
+    let mut idxs = 0..;
+    iter::from_fn(move || {
+        let mut items = Vec::new();
+        let total = match read_elf(&mut lines, |hp| items.push(hp)) {
+            Ok(total) => total?,
+            Err(err) => return Some(Err(err)),
+        };
+        Some(Ok(ElfInventory {
+            idx: idxs.next().unwrap(),
+            count: items.len(),
+            total,
+            items,
+        }))
+    })
+}
+
+/// Distribution of a quantity over the elves.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Summary {
+    /// Smallest value.
+    pub min: u64,
+    /// Largest value.
+    pub max: u64,
+    /// Arithmetic mean.
+    pub mean: f64,
+    /// 50th percentile.
+    pub median: f64,
+    /// Values of the [`PERCENTILES`], in the same order.
+    pub percentiles: Vec<f64>,
+}
+
+impl Summary {
+    /// Summary of the given values, `None` if there are none.
+    pub fn of(values: impl IntoIterator<Item = u64>) -> Option<Self> {
+        let mut values = values.into_iter().collect::<Vec<_>>();
+        values.sort_unstable();
+        let (&min, &max) = (values.first()?, values.last()?);
+        Some(Self {
+            min,
+            max,
+            // in floating point, sums of totals may not fit 64 bits
+            mean: values.iter().map(|&value| value as f64).sum::<f64>() / values.len() as f64,
+            median: percentile(&values, 50),
+            percentiles: PERCENTILES.map(|p| percentile(&values, p)).to_vec(),
+        })
+    }
+}
+
+/// Percentile `p` of non-empty sorted values, interpolating linearly between the two
+/// closest ranks (like numpy does by default).
+fn percentile(sorted: &[u64], p: u8) -> f64 {
+    let rank = f64::from(p) / 100.0 * (sorted.len() - 1) as f64;
+    let (below, above) = (sorted[rank.floor() as usize], sorted[rank.ceil() as usize]);
+    below as f64 + (above as f64 - below as f64) * rank.fract()
+}
+
+/// Statistics of the inventories of all elves.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    /// Number of elves.
+    pub nr_elves: usize,
+    /// Of the total hp per elf.
+    pub totals: Summary,
+    /// Of the number of items per elf.
+    pub item_counts: Summary,
+}
+
+impl Report {
+    /// Report on the given inventories, `None` if there are no elves.
+    pub fn new(inventories: &[ElfInventory]) -> Option<Self> {
+        Some(Self {
+            nr_elves: inventories.len(),
+            totals: Summary::of(inventories.iter().map(|elf| elf.total))?,
+            item_counts: Summary::of(inventories.iter().map(|elf| elf.count as u64))?,
+        })
+    }
+}
+
+/// A table with a row per quantity and a column per statistic.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} elves", self.nr_elves)?;
+        write!(
+            f,
+            "{:<12}{:>10}{:>10}{:>10}{:>10}",
+            "", "min", "max", "mean", "median"
+        )?;
+        for p in PERCENTILES {
+            write!(f, "{:>10}", format!("p{p}"))?;
+        }
+        for (name, summary) in [("total hp", &self.totals), ("items", &self.item_counts)] {
+            write!(
+                f,
+                "\n{name:<12}{:>10}{:>10}{:>10.1}{:>10.1}",
+                summary.min, summary.max, summary.mean, summary.median
+            )?;
+            for value in &summary.percentiles {
+                write!(f, "{value:>10.1}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write the per-elf table as CSV, with the items of an elf separated by spaces.
+pub fn write_csv(inventories: &[ElfInventory], mut out: impl Write) -> Result<()> {
+    writeln!(out, "idx,count,total,items")?;
+    for elf in inventories {
+        let items = elf.items.iter().map(u64::to_string).collect::<Vec<_>>();
+        writeln!(
+            out,
+            "{},{},{},{}",
+            elf.idx,
+            elf.count,
+            elf.total,
+            items.join(" ")
+        )?;
+    }
+    Ok(())
+}
+
+/// Write the per-elf table as JSON, an array with an object per elf.
+pub fn write_json(inventories: &[ElfInventory], mut out: impl Write) -> Result<()> {
+    serde_json::to_writer_pretty(&mut out, inventories)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+#[test]
+fn report_and_export_of_example() {
+    let lines = LineReader::new(include_str!("../input_test.txt").as_bytes());
+    let inventories = elf_inventories(lines).collect::<Result<Vec<_>>>().unwrap();
+    let second = &inventories[1];
+    assert_eq!(
+        (second.idx, &second.items, second.count, second.total),
+        (1, &vec![4000], 1, 4000)
+    );
+
+    let report = Report::new(&inventories).unwrap();
+    assert_eq!(report.nr_elves, 5);
+    // totals 4000, 6000, 10000, 11000, 24000
+    assert_eq!((report.totals.min, report.totals.max), (4000, 24000));
+    assert_eq!(
+        (report.totals.mean, report.totals.median),
+        (11000.0, 10000.0)
+    );
+    assert_eq!(
+        report.totals.percentiles,
+        [4800.0, 6000.0, 11000.0, 18800.0]
+    );
+    assert_eq!((report.item_counts.min, report.item_counts.max), (1, 3));
+    assert!(Report::new(&[]).is_none());
+
+    let mut csv = Vec::new();
+    write_csv(&inventories[..2], &mut csv).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "idx,count,total,items\n0,3,6000,1000 2000 3000\n1,1,4000,4000\n"
+    );
+    let mut json = Vec::new();
+    write_json(&inventories[1..2], &mut json).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([{ "idx": 1, "items": [4000], "count": 1, "total": 4000 }])
+    );
+}
@@ -0,0 +1,279 @@
+//! Advent of Code day 1
+//! <https://adventofcode.com/2022/day/1>
+#![warn(missing_docs)]
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+use std::iter;
+
+use anyhow::{anyhow, Result}; // "anyhow" crate wraps arbitrary error types so we don't have to.
+use aoc_common::combinators::{parse_line, uint};
+use aoc_common::{Answer, GenerateInput, InputRng, LineReader, NoParams, Solution};
+use rand::Rng;
+
+mod inventory;
+mod plot;
+
+pub use inventory::{
+    elf_inventories, write_csv, write_json, ElfInventory, Report, Summary, PERCENTILES,
+};
+pub use plot::{histogram, plot_totals, text_histogram, Bin};
+
+/// Day 1: Calorie Counting.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Params = NoParams;
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>> {
+        elf_totals(LineReader::new(input.as_bytes())).collect()
+    }
+
+    // the elf carrying the most
+    fn part1(total_hp_per_elf: &Vec<u64>, _params: &NoParams) -> Result<Answer> {
+        Ok(top_hp(total_hp_per_elf, 1)?.into())
+    }
+
+    // the three elves carrying the most
+    fn part2(total_hp_per_elf: &Vec<u64>, _params: &NoParams) -> Result<Answer> {
+        Ok(top_hp(total_hp_per_elf, 3)?.into())
+    }
+}
+
+/// `size` is the number of elves, at least three since part 2 adds up the top three.
+impl GenerateInput for Day01 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        let elves = (0..size.max(3))
+            .map(|_| {
+                let nr_items = rng.gen_range(1..=15);
+                (0..nr_items)
+                    .map(|_| format!("{}\n", rng.gen_range(1000..=60000)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        elves.join("\n")
+    }
+}
+
+/// Return the total hp represented by the food carried by each elf, reading the input
+/// lazily, one elf at a time.
+pub fn elf_totals<R: BufRead>(mut lines: LineReader<R>) -> impl Iterator<Item = Result<u64>> {
+    iter::from_fn(move || read_elf(&mut lines, |_hp| {}).transpose())
+}
+
+/// Read the food items of the next elf, handing the hp of each to `item`, and return
+/// their total, or `None` if there are no more elves.
+///
+/// The input is taken strictly: elves are separated by empty lines, and every other line
+/// has to be a number, so a typo can't split an elf in two. Lines holding nothing but
+/// whitespace are malformed, too. Empty lines at the start and the end of the input, or
+/// several in a row, are fine though, they don't make up elves carrying nothing. Totals
+/// that don't fit 64 bits fail with an [`OverflowError`].
+fn read_elf<R: BufRead>(
+    lines: &mut LineReader<R>,
+    mut item: impl FnMut(u64),
+) -> Result<Option<u64>> {
+    let Some(mut elf) = lines.next_group()? else {
+        return Ok(None);
+    };
+    let mut total = 0_u64;
+    while let Some(line) = elf.next_line()? {
+        if line.text.trim().is_empty() {
+            return Err(line
+                .error("a calorie count, or an empty line between elves")
+                .into());
+        }
+        let hp = parse_line(line, uint::<u64>("a calorie count"))?;
+        total = total
+            .checked_add(hp)
+            .ok_or(OverflowError { line: line.nr })?;
+        item(hp);
+    }
+    Ok(Some(total))
+}
+
+/// The total hp of an elf doesn't fit 64 bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    /// Line number of the item that made the total overflow, starting at 1.
+    pub line: usize,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}: the total hp of the elf is too large for 64 bits",
+            self.line
+        )
+    }
+}
+
+impl Error for OverflowError {}
+
+/// An elf, with the total hp of the food it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the input, starting at 0.
+    pub idx: usize,
+    /// Total hp of its food.
+    pub total: u64,
+}
+
+/// Elves carrying more rank higher, and of elves carrying the same the one coming first.
+impl Ord for Elf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total.cmp(&other.total).then(other.idx.cmp(&self.idx))
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `k` elves carrying the most among those seen so far. Elves come in one at a time
+/// and only the top `k` are kept, in a min-heap so the one to drop is always at hand.
+#[derive(Debug, Clone)]
+pub struct TopElves {
+    k: usize,
+    nr_seen: usize,
+    heap: BinaryHeap<Reverse<Elf>>,
+}
+
+impl TopElves {
+    /// Keep the top `k` elves.
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            nr_seen: 0,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    /// Add the next elf, carrying `total` hp.
+    pub fn push(&mut self, total: u64) {
+        let elf = Elf {
+            idx: self.nr_seen,
+            total,
+        };
+        self.nr_seen += 1;
+        self.heap.push(Reverse(elf));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The top elves, the one carrying the most first. Fewer than `k` if there
+    /// weren't as many elves.
+    pub fn into_sorted(self) -> Vec<Elf> {
+        // sorting the reversed elves ascending puts the top elf first
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(elf)| elf)
+            .collect()
+    }
+}
+
+impl Extend<u64> for TopElves {
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, totals: I) {
+        for total in totals {
+            self.push(total);
+        }
+    }
+}
+
+/// The `k` elves carrying the most, the one carrying the most first, reading the input
+/// lazily. Memory only depends on `k`, not on the number of elves.
+pub fn top_elves<R: BufRead>(lines: LineReader<R>, k: usize) -> Result<Vec<Elf>> {
+    let mut top_elves = TopElves::new(k);
+    for total in elf_totals(lines) {
+        top_elves.push(total?);
+    }
+    Ok(top_elves.into_sorted())
+}
+
+/// Total hp carried by the `k` elves carrying the most: the elf with maximum "hp" or
+/// "food points" for part 1, the three most-loaded elves for part 2.
+pub fn top_hp(total_hp_per_elf: &[u64], k: usize) -> Result<u64> {
+    let mut top_elves = TopElves::new(k);
+    top_elves.extend(total_hp_per_elf.iter().copied());
+    sum_hp(&top_elves.into_sorted())
+}
+
+/// Total hp carried by the given elves, failing if it doesn't fit 64 bits.
+pub fn sum_hp(elves: &[Elf]) -> Result<u64> {
+    elves
+        .iter()
+        .try_fold(0_u64, |sum, elf| sum.checked_add(elf.total))
+        .ok_or_else(|| anyhow!("The total hp of the top elves is too large for 64 bits."))
+}
+
+#[test]
+fn top_elves_of_example() {
+    let lines = LineReader::new(include_str!("../input_test.txt").as_bytes());
+    let top = top_elves(lines, 3).unwrap();
+    let top = top
+        .iter()
+        .map(|elf| (elf.idx, elf.total))
+        .collect::<Vec<_>>();
+    assert_eq!(top, [(3, 24000), (2, 11000), (4, 10000)]);
+}
+
+/// Of elves carrying the same, the first ones make it to the top.
+#[test]
+fn top_elves_ties_and_few_elves() {
+    let mut top_elves = TopElves::new(2);
+    top_elves.extend([5, 7, 5, 7, 5]);
+    let top = top_elves.into_sorted();
+    assert_eq!(top.iter().map(|elf| elf.idx).collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(top_hp(&[4, 9], 3).unwrap(), 13);
+    assert_eq!(top_hp(&[4, 9], 0).unwrap(), 0);
+    assert!(top_hp(&[u64::MAX, 1], 2).is_err());
+}
+
+/// Blank lines separate elves, however many there are and whatever the line endings.
+#[test]
+fn blank_lines_and_crlf() {
+    let totals = |input: &str| {
+        elf_totals(LineReader::new(input.as_bytes()))
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    };
+    assert_eq!(totals("1000\r\n2000\r\n\r\n\r\n3000\r\n\r\n"), [3000, 3000]);
+    assert_eq!(totals("\n\n1\n\n\n2\n\n\n"), [1, 2]);
+    assert_eq!(totals("1\r\n2\r"), [3]);
+    assert!(totals("").is_empty());
+}
+
+/// Malformed lines are errors pointing at them rather than separators, and totals are
+/// checked for overflow.
+#[test]
+fn malformed_lines_and_overflow() {
+    let error = |input: &str| {
+        elf_totals(LineReader::new(input.as_bytes()))
+            .collect::<Result<Vec<_>>>()
+            .unwrap_err()
+    };
+    let err = error("1\n\n2\n2x\n3\n");
+    let err = err.downcast_ref::<aoc_common::ParseError>().unwrap();
+    assert_eq!((err.line, err.column, err.found.as_str()), (4, 2, "x"));
+    let err = error("1\n \n2\n");
+    let err = err.downcast_ref::<aoc_common::ParseError>().unwrap();
+    assert_eq!(
+        (err.line, err.expected.as_str()),
+        (2, "a calorie count, or an empty line between elves")
+    );
+
+    let err = error(&format!("1\n\n{}\n2\n", u64::MAX - 1));
+    assert_eq!(err.downcast_ref(), Some(&OverflowError { line: 4 }));
+}
+
+aoc_common::example_tests!(Day01);
@@ -1,28 +1,88 @@
 /// Advent of Code day 1
 /// https://adventofcode.com/2022/day/1
-use anyhow::Result; // "anyhow" crate wraps arbitrary error types so we don't have to.
-use std::fs;
+use std::fs::File;
+use std::io::{BufRead, BufWriter};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use aoc_common::{InputArgs, LineReader, LogArgs};
+use clap::{Parser, Subcommand};
+use p1::{
+    elf_inventories, elf_totals, plot_totals, sum_hp, text_histogram, top_elves, write_csv,
+    write_json, Report,
+};
+use tracing::info;
+
+/// Width of the fullest bin of the text histogram.
+const HISTOGRAM_WIDTH: usize = 60;
+
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print statistics of the totals and item counts of the elves, instead of the answers.
+    Report {
+        /// Also write the inventory of each elf to this file, as CSV.
+        #[arg(long, value_name = "PATH")]
+        csv: Option<PathBuf>,
+        /// Also write the inventory of each elf to this file, as JSON.
+        #[arg(long, value_name = "PATH")]
+        json: Option<PathBuf>,
+    },
+    /// Draw a histogram of the totals of the elves in the terminal, instead of the answers.
+    Histogram {
+        /// Highlight this many elves carrying the most, as "*" instead of "#".
+        #[arg(short, long, default_value_t = 3)]
+        top: usize,
+        /// Number of bins.
+        #[arg(short, long, default_value_t = 20, value_parser = clap::value_parser!(u16).range(1..))]
+        bins: u16,
+        /// Also plot the histogram and the sorted totals to this PNG file.
+        #[arg(long, value_name = "PATH")]
+        png: Option<PathBuf>,
+    },
+}
 
 fn main() -> Result<()> {
-    let file_path = "./input.txt";
+    let cli = Cli::parse();
+    cli.log.init();
+    let lines = cli.input.open(env!("CARGO_MANIFEST_DIR"))?;
 
-    let hp_list = read_input_data(file_path)?;
-    let total_hp_per_elf = total_hp_per_elf(hp_list)?;
+    match cli.command {
+        Some(Command::Histogram { top, bins, png }) => {
+            print_histogram(lines, top, usize::from(bins), png.as_deref())
+        }
+        Some(Command::Report { csv, json }) => print_report(lines, csv.as_deref(), json.as_deref()),
+        None => print_answers(lines),
+    }
+}
+
+/// The answers of both parts.
+fn print_answers(lines: LineReader<impl BufRead>) -> Result<()> {
+    // the elves are read one by one, only the three most-loaded ones are kept
+    let top_three = top_elves(lines, 3)?;
 
     // find elf with maximum "hp" or "food points"
     // (solution to part 1)
-    let max_hp = total_hp_per_elf
-        .iter()
-        .max()
-        .expect("Couldn't find maximum, something wrong with input vector?");
-    println!("Elf with most food carries equivalent of {} hp.", &max_hp);
+    if let Some(elf) = top_three.first() {
+        println!(
+            "Elf with most food (number {}) carries equivalent of {} hp.",
+            elf.idx + 1,
+            elf.total
+        );
+    }
 
     // for part 2, we have to sum up the food carried by the three
-    // most-loaded elves. So we sort, reverse, and add up the first three entries.
-    let mut total_hp_sorted = total_hp_per_elf.clone();
-    total_hp_sorted.sort();
-    total_hp_sorted.reverse();
-    let max_three_hp: u32 = total_hp_sorted[..3].iter().sum();
+    // most-loaded elves.
+    let max_three_hp = sum_hp(&top_three)?;
     println!(
         "Three elves with most food carry a total equivalent of {} hp.",
         &max_three_hp
@@ -31,25 +91,44 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-/// Return the total hp represented by the food carried by each elf.
-fn total_hp_per_elf(hp_list: Vec<String>) -> Result<Vec<u32>> {
-    let hp_list_int = hp_list
-        .iter()
-        .map(|v| v.parse::<u32>().ok()) // casts into Option<u32>, empty strings result into NOne
-        .collect::<Vec<_>>();
-    let total_hp_per_elf = hp_list_int
-        .split(|line| line.is_none()) // now we can split at the None elements and get a vector of arrays of Option <u32>
-        .map(|group| group.iter().map(|v| v.unwrap()).sum::<u32>()) // map arrays into their sums
-        .collect();
-    Ok(total_hp_per_elf)
+/// See [`Command::Report`].
+fn print_report(
+    lines: LineReader<impl BufRead>,
+    csv: Option<&Path>,
+    json: Option<&Path>,
+) -> Result<()> {
+    let inventories = elf_inventories(lines).collect::<Result<Vec<_>>>()?;
+    match Report::new(&inventories) {
+        Some(report) => println!("{report}"),
+        None => println!("There are no elves."),
+    }
+    if let Some(path) = csv {
+        write_csv(&inventories, create(path)?)?;
+    }
+    if let Some(path) = json {
+        write_json(&inventories, create(path)?)?;
+    }
+    Ok(())
+}
+
+/// See [`Command::Histogram`].
+fn print_histogram(
+    lines: LineReader<impl BufRead>,
+    top: usize,
+    bins: usize,
+    png: Option<&Path>,
+) -> Result<()> {
+    let totals = elf_totals(lines).collect::<Result<Vec<_>>>()?;
+    print!("{}", text_histogram(&totals, top, bins, HISTOGRAM_WIDTH));
+    if let Some(path) = png {
+        plot_totals(&totals, top, bins, path)?;
+        info!("Plotted to {}.", path.display());
+    }
+    Ok(())
 }
 
-/// Just read everything into a string and split into a vector afterwards.
-/// Note that I started out using the "csv" crate, but this automatically
-/// removes empty lines, which act as delimiter between each elf's inventory
-/// here.
-fn read_input_data(file_path: &str) -> Result<Vec<String>> {
-    let hp_string = fs::read_to_string(file_path).expect("Reading file didn't work, wrong path?");
-    let hp_list: Vec<String> = hp_string.split('\n').map(String::from).collect();
-    Ok(hp_list)
+/// Create a file to export to.
+fn create(path: &Path) -> Result<BufWriter<File>> {
+    let file = File::create(path).with_context(|| format!("Couldn't create {}", path.display()))?;
+    Ok(BufWriter::new(file))
 }
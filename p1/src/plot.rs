@@ -0,0 +1,200 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use plotters::prelude::*;
+
+use crate::TopElves;
+
+/// Colour of the top elves in the charts, the others are grey.
+const TOP: RGBColor = RGBColor(200, 60, 40);
+const OTHERS: RGBColor = RGBColor(150, 150, 150);
+
+/// A bin of the histogram of the totals: the elves carrying `start..end` hp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bin {
+    /// Smallest total in the bin.
+    pub start: u64,
+    /// Smallest total of the next bin. It saturates at `u64::MAX`, the last bin includes
+    /// `u64::MAX` then.
+    pub end: u64,
+    /// Number of elves in the bin, top elves included.
+    pub nr_elves: usize,
+    /// Number of elves in the bin that are among the top `k`.
+    pub nr_top: usize,
+}
+
+/// Indices of the `k` elves carrying the most.
+fn top_idxs(totals: &[u64], k: usize) -> HashSet<usize> {
+    let mut top_elves = TopElves::new(k);
+    top_elves.extend(totals.iter().copied());
+    top_elves.into_sorted().iter().map(|elf| elf.idx).collect()
+}
+
+/// Split the range of the totals into `nr_bins` bins of equal width (at least one hp), and
+/// count the elves in each, separately for the `top_k` elves carrying the most.
+pub fn histogram(totals: &[u64], top_k: usize, nr_bins: usize) -> Vec<Bin> {
+    let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+        return Vec::new();
+    };
+    // in 128 bits, so totals up to u64::MAX don't overflow the bounds of the bins
+    let (min, max) = (u128::from(min), u128::from(max));
+    let width = (max - min) / nr_bins.max(1) as u128 + 1;
+    let bound = |idx: u128| u64::try_from(min + idx * width).unwrap_or(u64::MAX);
+    let nr_bins = (max - min) / width + 1;
+    let mut bins = (0..nr_bins)
+        .map(|idx| Bin {
+            start: bound(idx),
+            end: bound(idx + 1),
+            nr_elves: 0,
+            nr_top: 0,
+        })
+        .collect::<Vec<_>>();
+    let top = top_idxs(totals, top_k);
+    for (idx, &total) in totals.iter().enumerate() {
+        let bin_idx = ((u128::from(total) - min) / width).min(nr_bins - 1);
+        let bin = &mut bins[bin_idx as usize];
+        bin.nr_elves += 1;
+        bin.nr_top += usize::from(top.contains(&idx));
+    }
+    bins
+}
+
+/// Histogram of the totals for the terminal, a line per bin with a bar `width` chars long
+/// for the fullest bin. Top elves are drawn as "*", the others as "#".
+pub fn text_histogram(totals: &[u64], top_k: usize, nr_bins: usize, width: usize) -> String {
+    let bins = histogram(totals, top_k, nr_bins);
+    let most = bins.iter().map(|bin| bin.nr_elves).max().unwrap_or(0);
+    let label_width = bins.last().map_or(0, |bin| bin.end.to_string().len());
+    let mut text = String::new();
+    for bin in &bins {
+        // bins with any elves get at least one char, so none go unnoticed
+        let bar_len = |nr: usize| match nr {
+            0 => 0,
+            nr => (nr * width / most).max(1),
+        };
+        let nr_top = bar_len(bin.nr_top);
+        let nr_others = bar_len(bin.nr_elves).saturating_sub(nr_top);
+        let _ = writeln!(
+            text,
+            "{:>label_width$} - {:>label_width$} | {}{} {}",
+            bin.start,
+            if bin.end == u64::MAX {
+                bin.end
+            } else {
+                bin.end - 1
+            },
+            "*".repeat(nr_top),
+            "#".repeat(nr_others),
+            bin.nr_elves
+        );
+    }
+    text
+}
+
+/// Plot the totals to a PNG at `path`: the histogram on the left, and the totals sorted
+/// in descending order on the right, with the `top_k` elves highlighted in both.
+pub fn plot_totals(totals: &[u64], top_k: usize, nr_bins: usize, path: &Path) -> Result<()> {
+    let bins = histogram(totals, top_k, nr_bins);
+    let (Some(first), Some(last)) = (bins.first(), bins.last()) else {
+        bail!("There are no elves to plot.");
+    };
+    let root_area = BitMapBackend::new(path, (1400, 600)).into_drawing_area();
+    root_area.fill(&WHITE)?;
+    let (left, right) = root_area.split_horizontally(700);
+
+    let most = bins.iter().map(|bin| bin.nr_elves).max().unwrap_or(0);
+    let mut chart = ChartBuilder::on(&left)
+        .margin(20)
+        .set_label_area_size(LabelAreaPosition::Left, 60)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .caption("Elves by total hp", ("sans-serif", 20))
+        .build_cartesian_2d(first.start..last.end, 0..most + 1)?;
+    chart
+        .configure_mesh()
+        .x_desc("total hp")
+        .y_desc("elves")
+        .draw()?;
+    // the top elves are stacked on top of the others
+    chart.draw_series(bins.iter().map(|bin| {
+        let nr_others = bin.nr_elves - bin.nr_top;
+        Rectangle::new([(bin.start, 0), (bin.end, nr_others)], OTHERS.filled())
+    }))?;
+    chart.draw_series(bins.iter().filter(|bin| bin.nr_top > 0).map(|bin| {
+        let nr_others = bin.nr_elves - bin.nr_top;
+        Rectangle::new(
+            [(bin.start, nr_others), (bin.end, bin.nr_elves)],
+            TOP.filled(),
+        )
+    }))?;
+
+    let mut sorted = totals.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    let mut chart = ChartBuilder::on(&right)
+        .margin(20)
+        .set_label_area_size(LabelAreaPosition::Left, 80)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .caption("Total hp per elf, sorted", ("sans-serif", 20))
+        .build_cartesian_2d(0..sorted.len(), 0..sorted[0].saturating_add(1))?;
+    chart
+        .configure_mesh()
+        .x_desc("rank")
+        .y_desc("total hp")
+        .draw()?;
+    chart.draw_series(sorted.iter().enumerate().map(|(rank, &total)| {
+        let colour = if rank < top_k { TOP } else { OTHERS };
+        Rectangle::new([(rank, 0), (rank + 1, total)], colour.filled())
+    }))?;
+
+    root_area.present()?;
+    Ok(())
+}
+
+#[test]
+fn histogram_of_example() {
+    // totals of the example
+    let totals = [6000, 4000, 11000, 24000, 10000];
+    let bins = histogram(&totals, 3, 4);
+    let counts = bins
+        .iter()
+        .map(|bin| (bin.start, bin.nr_elves, bin.nr_top))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        counts,
+        [(4000, 2, 0), (9001, 2, 2), (14002, 0, 0), (19003, 1, 1)]
+    );
+    assert_eq!(
+        text_histogram(&totals, 1, 2, 4),
+        " 4000 - 14000 | #### 4\n14001 - 24001 | * 1\n"
+    );
+    assert!(histogram(&[], 3, 4).is_empty());
+}
+
+/// The bounds of the bins don't overflow with totals up to the largest there is.
+#[test]
+fn histogram_of_huge_totals() {
+    let bins = histogram(&[0, u64::MAX], 1, 1);
+    assert_eq!(
+        bins,
+        [Bin {
+            start: 0,
+            end: u64::MAX,
+            nr_elves: 2,
+            nr_top: 1
+        }]
+    );
+    let bins = histogram(&[0, u64::MAX, u64::MAX / 2], 0, 2);
+    let bounds = bins
+        .iter()
+        .map(|bin| (bin.start, bin.end, bin.nr_elves))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        bounds,
+        [(0, u64::MAX / 2 + 1, 2), (u64::MAX / 2 + 1, u64::MAX, 1)]
+    );
+    assert_eq!(
+        text_histogram(&[0, u64::MAX], 1, 1, 4),
+        format!("{:>20} - {} | **## 2\n", 0, u64::MAX)
+    );
+}
@@ -0,0 +1,350 @@
+//! Advent of Code day 10
+//! <https://adventofcode.com/2022/day/10>
+//!
+//! Part 1 turned out more awkward than initially expected. There is probably
+//! a much better way of exposing the register value at a given cycle count.
+//! Part 2 was much more fun, I used the "plotters" crate to visualize the CRT output.
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+use std::num::NonZeroU16;
+
+use anyhow::Result;
+use aoc_common::combinators::{alt, int, map, parse_lines, preceded, tag};
+use aoc_common::{
+    frame_stride, Answer, Frame, GenerateInput, InputRng, ParseError, Rgb, Solution, Visualize,
+};
+use plotters::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use tracing::warn;
+
+/// Width of the CRT of the puzzle description.
+const CRT_WIDTH: i32 = 40;
+
+/// Colours of dark and lit CRT pixels in the visualisation.
+const DARK_PIXEL: Rgb = [16, 16, 32];
+const LIT_PIXEL: Rgb = [255, 200, 40];
+
+/// Day 10: Cathode-Ray Tube.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Params = Params;
+    type Input<'a> = Vec<Instructions>;
+
+    fn parse(input: &str) -> Result<Vec<Instructions>> {
+        Ok(parse_instructions(input.lines().collect())?)
+    }
+
+    fn part1(instrs: &Vec<Instructions>, params: &Params) -> Result<Answer> {
+        Ok(signal_strength(instrs.clone(), params.probe_cycles.clone()).into())
+    }
+
+    fn part2(instrs: &Vec<Instructions>, params: &Params) -> Result<Answer> {
+        let (_state, _signal_hist, drawn_pixels) =
+            simulate_cpu(instrs.clone(), None, params.crt_width());
+        Ok(render_crt_text(&drawn_pixels, params.crt_width()).into())
+    }
+}
+
+/// `size` is the number of instructions, or more if needed to reach the last probe
+/// cycle. Register X wanders about within the CRT width, like in the puzzle input.
+impl GenerateInput for Day10 {
+    fn generate_input(rng: &mut InputRng, size: usize, params: &Params) -> String {
+        let last_cycle = params.probe_cycles.iter().max().copied().unwrap_or(0);
+        let (mut nr_instrs, mut cycles, mut x) = (0, 0, 1);
+        let mut program = String::new();
+        while nr_instrs < size || cycles < last_cycle {
+            if rng.gen_bool(0.4) {
+                program += "noop\n";
+                cycles += 1;
+            } else {
+                let mut val = rng.gen_range(-10..=10);
+                if !(0..params.crt_width()).contains(&(x + val)) {
+                    val = -val;
+                }
+                x += val;
+                program += &format!("addx {val}\n");
+                cycles += 2;
+            }
+            nr_instrs += 1;
+        }
+        program
+    }
+}
+
+/// The CRT lighting up pixel by pixel while the program runs, ending with the image of
+/// part 2.
+impl Visualize for Day10 {
+    fn frames(instrs: &Vec<Instructions>, params: &Params) -> Result<Vec<Frame>> {
+        let (_state, _signal_hist, drawn_pixels) =
+            simulate_cpu(instrs.clone(), None, params.crt_width());
+        let nr_rows = drawn_pixels.iter().map(|(_x, y)| *y).max().unwrap_or(0);
+        let mut crt = Frame::new(
+            usize::from(params.crt_width.get()),
+            nr_rows as usize,
+            DARK_PIXEL,
+        );
+        let stride = frame_stride(drawn_pixels.len());
+        let mut frames = Vec::new();
+        for (idx, &(x, y)) in drawn_pixels.iter().enumerate() {
+            if idx % stride == 0 {
+                frames.push(crt.clone());
+            }
+            crt[(x as usize, y as usize - 1)] = LIT_PIXEL; // rows count from 1
+        }
+        frames.push(crt);
+        Ok(frames)
+    }
+
+    fn glyph(colour: Rgb) -> char {
+        if colour == LIT_PIXEL {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Puzzle parameters, see `[day10]` in aoc.toml.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Number of pixels per CRT row (part 2), at least one.
+    pub crt_width: NonZeroU16,
+    /// Cycles at which the register value is probed for the signal strength (part 1).
+    pub probe_cycles: Vec<u32>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            crt_width: NonZeroU16::new(CRT_WIDTH as u16).unwrap(),
+            probe_cycles: vec![20, 60, 100, 140, 180, 220],
+        }
+    }
+}
+
+impl Params {
+    /// The CRT width as the simulation takes it.
+    pub fn crt_width(&self) -> i32 {
+        i32::from(self.crt_width.get())
+    }
+}
+
+/// Enum encoding the different operation types.
+#[derive(Debug, Clone, Copy)]
+pub enum Instructions {
+    /// Do nothing for one cycle.
+    Noop,
+    /// Add the value to register X, which takes two cycles.
+    Addx(i32),
+}
+impl Instructions {
+    fn cycles(self) -> u32 {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_i32) => 2,
+        }
+    }
+}
+
+/// This models the current state of the CPU: currently executed instruction,
+/// total cycle number since simulation start, current register value.
+#[derive(Debug)]
+pub struct CPUstate {
+    curr_inst: Option<Instructions>,
+    curr_cycle: u32,
+    x: i32,
+}
+impl Default for CPUstate {
+    fn default() -> Self {
+        Self {
+            curr_inst: None,
+            curr_cycle: 0,
+            x: 1,
+        }
+    }
+}
+
+/// Parses vector containing input file lines into vector with "Instructions" type entries.
+pub fn parse_instructions(instr_list: Vec<&str>) -> Result<Vec<Instructions>, ParseError> {
+    let instr = alt((
+        map(tag("noop"), |_| Instructions::Noop),
+        map(
+            preceded(tag("addx "), int("a number to add")),
+            Instructions::Addx,
+        ),
+    ));
+    parse_lines(instr_list, instr)
+}
+
+/// Register values at the probe cycles, as pairs of cycle number and value.
+pub type SignalHist = Vec<(u32, i32)>;
+
+/// This function runs the simulation and returns the state at the end of the simulation,
+/// as well as the register value at cycle numbers passed in the "probe_cycle" vector
+/// (as pairs of cycle number and value, in the order of the cycles),
+/// as well as a vector of tuples, each representing the coordinate of a drawn pixel.
+pub fn simulate_cpu(
+    instrs: Vec<Instructions>,
+    probe_cycles: Option<Vec<u32>>,
+    crt_width: i32,
+) -> (CPUstate, SignalHist, Vec<(i32, i32)>) {
+    let mut state: CPUstate = Default::default();
+    let mut signal_hist: SignalHist = Vec::new();
+    let mut drawn_pixels: Vec<(i32, i32)> = Vec::new();
+    for instr in instrs {
+        state.curr_inst = Some(instr);
+        for _cyc in 0..state.curr_inst.unwrap().cycles() {
+            let curr_cycle: i32 = state.curr_cycle.try_into().unwrap();
+            if ((curr_cycle % crt_width) - state.x).abs() < 2 {
+                drawn_pixels.push((curr_cycle % crt_width, (curr_cycle / crt_width + 1)));
+            }
+            state.curr_cycle += 1;
+            if probe_cycles.is_some() && probe_cycles.clone().unwrap().contains(&state.curr_cycle) {
+                signal_hist.push((state.curr_cycle, state.x));
+            }
+        }
+
+        match &state.curr_inst {
+            Some(Instructions::Noop) => {}
+            Some(Instructions::Addx(val)) => {
+                state.x += val;
+            }
+            _ => {
+                warn!(cycle = state.curr_cycle, "no instruction to execute");
+            }
+        }
+    }
+    (state, signal_hist, drawn_pixels)
+}
+
+/// Display CRT image as scatter plot using "plotters" crate,
+/// using a vector of coordinate tuples as input. The rows (counting from 1) go down,
+/// so the image isn't upside down.
+#[allow(clippy::reversed_empty_ranges)] // the y range is reversed on purpose, see above
+pub fn plot_crt_image(crt_image: Vec<(i32, i32)>, crt_width: i32) -> Result<()> {
+    let nr_rows = crt_image.iter().map(|(_x, y)| *y).max().unwrap_or(0);
+    let store_str = String::from("CRT_image.png");
+    let root_area = BitMapBackend::new(&store_str, (1200, 800)).into_drawing_area();
+    root_area.fill(&WHITE)?;
+
+    let mut ctx = ChartBuilder::on(&root_area)
+        .set_label_area_size(LabelAreaPosition::Left, 80)
+        .set_label_area_size(LabelAreaPosition::Right, 80)
+        .set_label_area_size(LabelAreaPosition::Bottom, 80)
+        .set_label_area_size(LabelAreaPosition::Top, 80)
+        .caption("CRT image", ("sans-serif", 18))
+        .build_cartesian_2d(-1..crt_width, nr_rows + 1..0)?;
+
+    ctx.configure_mesh().draw()?;
+
+    ctx.draw_series(crt_image.iter().map(|point| {
+        Circle::new(
+            *point,
+            10,
+            Into::<ShapeStyle>::into(&RGBColor(0, 0, 0)).filled(),
+        )
+    }))?;
+
+    root_area.present()?;
+    Ok(())
+}
+
+/// Signal strength for part 1: the sum of the register values during the "probe cycles",
+/// each multiplied by its cycle number. The probe cycles can come in any order, and a
+/// cycle probed twice counts twice. Cycles the program doesn't reach don't count.
+pub fn signal_strength(instrs: Vec<Instructions>, probe_cycles: Vec<u32>) -> i32 {
+    // the CRT image isn't needed here, so its width doesn't matter
+    let (_state, signal_hist, _drawn_pixels) =
+        simulate_cpu(instrs, Some(probe_cycles.clone()), CRT_WIDTH);
+
+    // look up the value of each probe cycle, rather than relying on the order of both
+    let x_at = signal_hist.into_iter().collect::<HashMap<_, _>>();
+    probe_cycles
+        .iter()
+        .filter_map(|cycle| Some(x_at.get(cycle)? * *cycle as i32))
+        .sum()
+}
+
+/// Text version of the CRT image for part 2, with "#" for lit and "." for dark pixels.
+pub fn render_crt_text(crt_image: &[(i32, i32)], crt_width: i32) -> String {
+    let nr_rows = crt_image.iter().map(|(_x, y)| *y).max().unwrap_or(0);
+    let mut rows = Vec::new();
+    for y in 1..=nr_rows {
+        let row: String = (0..crt_width)
+            .map(|x| {
+                if crt_image.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        rows.push(row);
+    }
+    rows.join("\n")
+}
+
+/// Very basic test for part 1.
+#[test]
+fn check_x_part1_example1() {
+    let instr_list = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+
+    let instrs = parse_instructions(instr_list).unwrap();
+    let (state, _signal_hist, _drawn_pixels) = simulate_cpu(instrs, None, CRT_WIDTH);
+
+    assert_eq!(state.x, -1);
+}
+
+/// Test if signal strength calculation works on more elaborate example data
+/// from the description of part 1.
+#[test]
+fn check_x_part1_example2() {
+    let instr_list = include_str!("../input_test2.txt")
+        .lines()
+        .collect::<Vec<_>>();
+
+    let instrs = parse_instructions(instr_list).unwrap();
+    let probe_cycles: Vec<u32> = vec![20, 60, 100, 140, 180, 220];
+    let (_state, signal_hist, _drawn_pixels) =
+        simulate_cpu(instrs, Some(probe_cycles.clone()), CRT_WIDTH);
+
+    let signal_strength: i32 = signal_hist
+        .iter()
+        .map(|(cycle, x)| *x * *cycle as i32)
+        .sum();
+    assert_eq!(signal_strength, 13140);
+}
+
+/// Probe cycles in any order, repeated or out of reach are summed up correctly.
+#[test]
+fn check_unsorted_probe_cycles() {
+    let instr_list = include_str!("../input_test2.txt")
+        .lines()
+        .collect::<Vec<_>>();
+    let instrs = parse_instructions(instr_list).unwrap();
+
+    // 220 * 18, 20 * 21 and 100 * 18, see the puzzle description
+    assert_eq!(signal_strength(instrs.clone(), vec![220, 20, 100]), 6180);
+    assert_eq!(signal_strength(instrs.clone(), vec![20, 20]), 840);
+    assert_eq!(signal_strength(instrs, vec![1000, 20]), 420);
+}
+
+/// A CRT without pixels is rejected with the parameters, before anything runs.
+#[test]
+fn zero_crt_width() {
+    let config = aoc_common::ConfigArgs {
+        config: None,
+        overrides: vec!["crt_width=0".parse().unwrap()],
+    };
+    let config = config.load(env!("CARGO_MANIFEST_DIR"), false).unwrap();
+    assert!(config.params::<Params>(10).is_err());
+}
+
+aoc_common::example_tests!(Day10);
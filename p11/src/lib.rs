@@ -0,0 +1,466 @@
+//! Advent of Code day 11
+//! <https://adventofcode.com/2022/day/11>
+//!
+//! Monkeying around with a hand-written parser, again. These days it's put together
+//! from the combinators of "aoc_common", which are much like the "nom" crate.
+//!
+//! Otherwise, needed to look at other people's solutions for hints on this.
+//! First, issues with borrow checker with loops accessing both the monkey vector
+//! and its elements. Resolved by looping over index and having only a mutable borrow
+//! to a singular monkey out of the vector.
+//!
+//! Second, I needed hints for part 2. Never heard of the "Chinese Remainder Theorem"
+//! before. On the bright side, I made use of passing functions as arguments for the
+//! first time in Rust.
+#![warn(missing_docs)]
+
+use std::num::NonZeroU64;
+
+use anyhow::Result;
+use aoc_common::combinators::{
+    alt, blocks, delimited, labelled, map, preceded, satisfy, separated_list0, separated_pair,
+    spanned, tag, try_map, uint,
+};
+use aoc_common::{Answer, GenerateInput, InputLine, InputRng, ParseError, Solution};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::Deserialize;
+use tracing::warn;
+
+/// Day 11: Monkey in the Middle.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Params = Params;
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>> {
+        Ok(monkey_parser(input.lines().collect())?)
+    }
+
+    fn part1(monkeys: &Vec<Monkey>, params: &Params) -> Result<Answer> {
+        let score = solution_part1(
+            monkeys.clone(),
+            params.rounds_part1,
+            params.worry_divisor.get(),
+        );
+        Ok(score.into())
+    }
+
+    fn part2(monkeys: &Vec<Monkey>, params: &Params) -> Result<Answer> {
+        Ok(solution_part2(monkeys.clone(), params.rounds_part2).into())
+    }
+}
+
+/// `size` is the number of items, which are spread over up to eight monkeys. Their
+/// divisors are different primes, whose product needs to stay small enough for the
+/// worry levels of part 2 to be squared without overflowing.
+impl GenerateInput for Day11 {
+    fn generate_input(rng: &mut InputRng, size: usize, params: &Params) -> String {
+        let nr_monkeys = (size / 4).clamp(2, 8);
+        // Multiplying monkeys (and the one squaring worry levels) may well drive the worry
+        // levels of part 1 out of range, so try a few times before leaving them out.
+        let monkeys = (0..100)
+            .map(|_| random_monkeys(rng, nr_monkeys, size, true))
+            .find(|monkeys| part1_in_range(monkeys.clone(), params))
+            .unwrap_or_else(|| random_monkeys(rng, nr_monkeys, size, false));
+
+        let descriptions = monkeys
+            .iter()
+            .enumerate()
+            .map(|(idx, monkey)| {
+                let items = monkey.items.iter().map(u64::to_string).collect::<Vec<_>>();
+                let operand = monkey.fac.map_or("old".to_owned(), |fac| fac.to_string());
+                format!(
+                    "Monkey {idx}:\n  Starting items: {}\n  Operation: new = old {} {operand}\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}\n",
+                    items.join(", "),
+                    monkey.op_op,
+                    monkey.div,
+                    monkey.receiver_true,
+                    monkey.receiver_false
+                )
+            })
+            .collect::<Vec<_>>();
+        descriptions.join("\n")
+    }
+}
+
+/// Puzzle parameters, see `[day11]` in aoc.toml.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Rounds of monkey business in part 1.
+    pub rounds_part1: usize,
+    /// Rounds of monkey business in part 2.
+    pub rounds_part2: usize,
+    /// Relief after each inspection in part 1: worry levels are divided by this, which
+    /// can't be 0.
+    pub worry_divisor: NonZeroU64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            rounds_part1: 20,
+            rounds_part2: 10000,
+            worry_divisor: NonZeroU64::new(3).unwrap(),
+        }
+    }
+}
+
+/// The monkey struct.
+#[derive(Debug, Clone, Default)]
+pub struct Monkey {
+    items: Vec<u64>,
+    div: u64,            // Divisor, see input data
+    op_op: char,         // Operator used in "Operation" (+ or *), see input data
+    fac: Option<u64>, // Second operand for "Operation". If None, use "item" (worry value), see e.g. Monkey 2 in test input data.
+    receiver_true: u64, // Index of monkey being thrown to if test condition is true
+    receiver_false: u64, // Index of monkey being thrown to if test condition is false
+    insp_items: u64,  // Number of times this monkey has inspected an item
+}
+impl Monkey {
+    /// Model inspection operation. Takes function "f" to apply "worry reduction"
+    /// (either division or remainder for part 1 and 2, respectively).
+    fn insp_op(
+        item: &u64,
+        op: &char,
+        fac: &Option<u64>,
+        f: &dyn Fn(u64, u64) -> u64,
+        worry_div: u64,
+    ) -> u64 {
+        let operand: u64;
+        if let Some(op) = fac {
+            operand = *op;
+        } else {
+            operand = *item;
+        }
+
+        match op {
+            '+' => f(*item + operand, worry_div),
+            '*' => f(*item * operand, worry_div),
+            _ => {
+                warn!(%op, "can't update item value, error with item or monkey?");
+                *item
+            }
+        }
+    }
+
+    /// Returns monkey an "item" is thrown to.
+    fn receiver(&self, item: &u64) -> usize {
+        if (*item).is_multiple_of(self.div) {
+            self.receiver_true.try_into().unwrap()
+        } else {
+            self.receiver_false.try_into().unwrap()
+        }
+    }
+}
+
+/// Divide worry by 3 after inspection, see instructions for part 1.
+fn worry_reducer_part1(worry: u64, reducer: u64) -> u64 {
+    worry / reducer
+}
+
+/// Remainder operation to reduce ginormous "worry levels".
+fn worry_reducer_part2(value: u64, reducer: u64) -> u64 {
+    value % reducer
+}
+
+/// The great monkey parser. Each monkey is a block of lines, which have to come in the
+/// order of the puzzle description. Monkeys are numbered from 0 in order, there have to
+/// be at least two of them, divisors can't be 0, and monkeys can only throw to monkeys
+/// that exist.
+pub fn monkey_parser(input_data: Vec<&str>) -> Result<Vec<Monkey>, ParseError> {
+    let end_of_input = InputLine::new(input_data.len() + 1, "");
+    let monkey_nr = || uint::<u64>("a monkey number");
+    let operand = alt((map(tag("old"), |_| None), map(uint("a number"), Some)));
+    let operation = preceded(
+        tag("new = old "),
+        separated_pair(
+            satisfy(|op| op == '*' || op == '+', "an operation using '*' or '+'"),
+            tag(" "),
+            operand,
+        ),
+    );
+    let throw_to = || preceded(tag("throw to monkey "), spanned(monkey_nr()));
+
+    let mut monkeys = Vec::new();
+    // receivers are checked once the number of monkeys is known
+    let mut receivers = Vec::new();
+    for (idx, mut block) in (0..).zip(blocks(input_data)) {
+        // monkeys are numbered in order, as the receivers refer to them by their index
+        let header_nr = try_map(monkey_nr(), |nr| match nr {
+            nr if nr == idx => Ok(nr),
+            _ => Err(format!("monkey number {idx}")),
+        });
+        block.line(delimited(tag("Monkey "), header_nr, tag(":")))?;
+        let items = block.line(labelled(
+            "Starting items",
+            separated_list0(tag(", "), uint("a worry level")),
+        ))?;
+        let (op_op, fac) = block.line(labelled("Operation", &operation))?;
+        let div = block.line(labelled(
+            "Test",
+            preceded(
+                tag("divisible by "),
+                try_map(uint("a divisor"), |div| match div {
+                    0 => Err("a divisor other than 0".to_owned()),
+                    div => Ok(div),
+                }),
+            ),
+        ))?;
+        let (token_true, receiver_true) = block.line(labelled("If true", throw_to()))?;
+        let (token_false, receiver_false) = block.line(labelled("If false", throw_to()))?;
+        block.finish()?;
+        let lines = block.lines();
+        receivers.push((lines[4], token_true, receiver_true));
+        receivers.push((lines[5], token_false, receiver_false));
+        monkeys.push(Monkey {
+            items,
+            div,
+            op_op,
+            fac,
+            receiver_true,
+            receiver_false,
+            insp_items: 0,
+        });
+    }
+    if monkeys.len() < 2 {
+        return Err(end_of_input.error("at least two monkeys"));
+    }
+    for (line, token, receiver) in receivers {
+        if receiver >= monkeys.len() as u64 {
+            let expected = format!("a monkey number below {}", monkeys.len());
+            return Err(line.error_at(token, expected));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Execute one round of inspection and throwing.
+fn execute_round(monkeys: &mut [Monkey], reducer: &dyn Fn(u64, u64) -> u64, worry_div: u64) {
+    for m_idx in 0..monkeys.len() {
+        let monkey = &mut monkeys[m_idx];
+        // inspect and update worry levels
+        monkey.items = monkey
+            .items
+            .iter_mut()
+            .map(|e| {
+                monkey.insp_items += 1;
+                Monkey::insp_op(e, &monkey.op_op, &monkey.fac, reducer, worry_div)
+            })
+            .collect();
+        // throw items
+        for (i_idx, item) in monkeys[m_idx].clone().items.iter().enumerate() {
+            let rec_idx = monkeys[m_idx].receiver(&monkeys[m_idx].items[i_idx]);
+            monkeys[rec_idx].items.push(*item);
+        }
+        monkeys[m_idx].items.clear();
+    }
+}
+
+/// Solution for part 1: 20 rounds in the puzzle, with worry levels divided by 3 after
+/// each inspection.
+pub fn solution_part1(mut monkeys: Vec<Monkey>, rounds: usize, worry_divisor: u64) -> u64 {
+    for _round in 0..rounds {
+        execute_round(&mut monkeys, &worry_reducer_part1, worry_divisor);
+    }
+    // get number of inspections performed by each monkey
+    let mut inspections = monkeys.iter().map(|e| e.insp_items).collect::<Vec<_>>();
+
+    inspections.sort(); // sort in ascending order
+    let inspections = inspections.iter().rev().collect::<Vec<_>>(); // reverse order
+    inspections[0] * inspections[1] // score, see puzzle description
+}
+
+/// Solution for part 2: 10000 rounds in the puzzle, keeping the worry levels in check
+/// with the product of all divisors.
+pub fn solution_part2(mut monkeys: Vec<Monkey>, rounds: usize) -> u64 {
+    let div_prod = monkeys.iter().map(|e| e.div).product();
+    // TBH i have no idea why the remainder operation using "div_prod"
+    // leaves the division check unaffected. But I am not sure if I care sufficiently.
+
+    for _round in 0..rounds {
+        execute_round(&mut monkeys, &worry_reducer_part2, div_prod);
+    }
+
+    let mut inspections = monkeys.iter().map(|e| e.insp_items).collect::<Vec<_>>();
+
+    inspections.sort();
+    let inspections = inspections.iter().rev().collect::<Vec<_>>();
+    inspections[0] * inspections[1]
+}
+
+/// Random monkeys for the input generator, with `nr_items` items spread over them.
+/// Without `multiply`, they only add to the worry levels.
+fn random_monkeys(
+    rng: &mut InputRng,
+    nr_monkeys: usize,
+    nr_items: usize,
+    multiply: bool,
+) -> Vec<Monkey> {
+    let mut divisors = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    divisors.shuffle(rng);
+    let squaring_idx = rng.gen_range(0..nr_monkeys);
+    let mut monkeys = (0..nr_monkeys)
+        .map(|idx| {
+            let (op_op, fac) = if multiply && idx == squaring_idx {
+                ('*', None)
+            } else if multiply && rng.gen_bool(0.5) {
+                ('*', Some(rng.gen_range(2..=19)))
+            } else {
+                ('+', Some(rng.gen_range(1..=8)))
+            };
+            // two different monkeys to throw to, unless there is just one other monkey
+            let others = (0..nr_monkeys as u64)
+                .filter(|&other| other != idx as u64)
+                .collect::<Vec<_>>();
+            let receivers = others.choose_multiple(rng, 2).copied().collect::<Vec<_>>();
+            Monkey {
+                div: divisors[idx],
+                op_op,
+                fac,
+                receiver_true: receivers[0],
+                receiver_false: *receivers.last().unwrap(),
+                ..Default::default()
+            }
+        })
+        .collect::<Vec<_>>();
+    for _item in 0..nr_items {
+        let worry = rng.gen_range(50..=99);
+        monkeys[rng.gen_range(0..nr_monkeys)].items.push(worry);
+    }
+    monkeys
+}
+
+/// Whether the worry levels of part 1 stay within range for all rounds. Same as
+/// "execute_round", but with overflow checks.
+fn part1_in_range(mut monkeys: Vec<Monkey>, params: &Params) -> bool {
+    for _round in 0..params.rounds_part1 {
+        for m_idx in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[m_idx].items) {
+                let monkey = &monkeys[m_idx];
+                let operand = monkey.fac.unwrap_or(item);
+                let worry = match monkey.op_op {
+                    '+' => item.checked_add(operand),
+                    _ => item.checked_mul(operand),
+                };
+                let Some(worry) = worry else {
+                    return false;
+                };
+                let worry = worry / params.worry_divisor.get();
+                let receiver = monkey.receiver(&worry);
+                monkeys[receiver].items.push(worry);
+            }
+        }
+    }
+    true
+}
+
+#[test]
+fn test_monkey_slinging_part1() {
+    let input_data = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+    let mut monkeys = monkey_parser(input_data).unwrap();
+
+    for _round in 0..20 {
+        execute_round(&mut monkeys, &worry_reducer_part1, 3);
+    }
+
+    let mut inspections = monkeys.iter().map(|e| e.insp_items).collect::<Vec<_>>();
+
+    inspections.sort();
+    let inspections = inspections.iter().rev().collect::<Vec<_>>();
+    let score = inspections[0] * inspections[1];
+    assert_eq!(score, 10605);
+}
+
+#[test]
+fn test_monkey_slinging_part2() {
+    let input_data = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+    let mut monkeys = monkey_parser(input_data).unwrap();
+    let div_prod = monkeys.iter().map(|e| e.div).product();
+
+    for _round in 0..10000 {
+        execute_round(&mut monkeys, &worry_reducer_part2, div_prod);
+    }
+
+    let mut inspections = monkeys.iter().map(|e| e.insp_items).collect::<Vec<_>>();
+
+    inspections.sort();
+    let inspections = inspections.iter().rev().collect::<Vec<_>>();
+    let score = inspections[0] * inspections[1];
+    assert_eq!(score, 2713310158);
+}
+
+#[test]
+fn test_monkey_parser_reports_position() {
+    let input_data = vec!["Monkey 0:", "  Starting items: 79, x8"];
+    let err = monkey_parser(input_data).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (2, 23, "x8"));
+}
+
+/// Parse the example with `from` replaced by `to`, which has to fail.
+#[cfg(test)]
+fn example_error(from: &str, to: &str) -> ParseError {
+    let input = include_str!("../input_test.txt").replacen(from, to, 1);
+    monkey_parser(input.lines().collect()).unwrap_err()
+}
+
+/// Monkeys can't throw to monkeys that don't exist.
+#[test]
+fn test_monkey_parser_rejects_unknown_receiver() {
+    let err = example_error("throw to monkey 3", "throw to monkey 7");
+    assert_eq!((err.line, err.column, err.found.as_str()), (6, 31, "7"));
+    assert_eq!(err.expected, "a monkey number below 4");
+}
+
+/// Monkeys have to be numbered in order.
+#[test]
+fn test_monkey_parser_rejects_misnumbered_monkey() {
+    let err = example_error("Monkey 2:", "Monkey 5:");
+    assert_eq!((err.line, err.column, err.found.as_str()), (15, 8, "5"));
+    assert_eq!(err.expected, "monkey number 2");
+}
+
+/// Divisors of 0 would make part 2 divide by 0.
+#[test]
+fn test_monkey_parser_rejects_zero_divisor() {
+    let err = example_error("divisible by 23", "divisible by 0");
+    assert_eq!((err.line, err.column, err.found.as_str()), (4, 22, "0"));
+}
+
+/// Monkey business takes two monkeys.
+#[test]
+fn test_monkey_parser_rejects_single_monkey() {
+    let one_monkey = include_str!("../input_test.txt")
+        .split("\n\n")
+        .next()
+        .unwrap();
+    let err = monkey_parser(one_monkey.lines().collect()).unwrap_err();
+    assert_eq!(
+        (err.line, err.expected.as_str()),
+        (7, "at least two monkeys")
+    );
+    let err = monkey_parser(Vec::new()).unwrap_err();
+    assert_eq!(err.expected, "at least two monkeys");
+}
+
+/// No relief at all is rejected with the parameters, before anything runs.
+#[test]
+fn test_zero_worry_divisor() {
+    let config = aoc_common::ConfigArgs {
+        config: None,
+        overrides: vec!["worry_divisor=0".parse().unwrap()],
+    };
+    let config = config.load(env!("CARGO_MANIFEST_DIR"), false).unwrap();
+    assert!(config.params::<Params>(11).is_err());
+}
+
+aoc_common::example_tests!(Day11);
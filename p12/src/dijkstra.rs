@@ -0,0 +1,84 @@
+//! Dijkstra the hand-written way, courtesy of ChatGPT. Not used for the solution, which
+//! relies on the "pathfinding" crate, but kept as an oracle to check the solution against.
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+
+use crate::{Board, Pos};
+
+#[derive(Eq, PartialEq, Debug)]
+struct State {
+    cost: i64,
+    node: i64,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cost of the cheapest way from `start` to `end`, if there is one. Every node of the
+/// graph needs an entry, even if there are no edges leaving it.
+pub fn dijkstra(graph: &HashMap<i64, Vec<(i64, i64)>>, start: i64, end: i64) -> Option<i64> {
+    let mut heap = BinaryHeap::new();
+    heap.push(State {
+        cost: 0,
+        node: start,
+    });
+    let mut distances: HashMap<i64, i64> = HashMap::new();
+    distances.insert(start, 0);
+    while let Some(State { cost, node }) = heap.pop() {
+        if node == end {
+            return Some(cost);
+        }
+        if cost > distances[&node] {
+            continue;
+        }
+        for &(neighbour, cost) in graph.get(&node).unwrap().iter() {
+            let new_cost = distances[&node] + cost;
+            if distances.contains_key(&neighbour) {
+                if new_cost < distances[&neighbour] {
+                    distances.insert(neighbour, new_cost);
+                    heap.push(State {
+                        cost: new_cost,
+                        node: neighbour,
+                    });
+                }
+            } else {
+                distances.insert(neighbour, new_cost);
+                heap.push(State {
+                    cost: new_cost,
+                    node: neighbour,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Number of a position as a node of the graph.
+pub fn node(board: &Board, (x, y): Pos) -> i64 {
+    (y * board.heights.width() + x) as i64
+}
+
+/// Graph of the steps allowed on the board, each costing 1.
+pub fn step_graph(board: &Board) -> HashMap<i64, Vec<(i64, i64)>> {
+    let mut graph = HashMap::new();
+    for y in 0..board.heights.height() {
+        for x in 0..board.heights.width() {
+            let steps = board
+                .get_successors(&(x, y))
+                .into_iter()
+                .map(|(pos, _cost)| (node(board, pos), 1))
+                .collect();
+            graph.insert(node(board, (x, y)), steps);
+        }
+    }
+    graph
+}
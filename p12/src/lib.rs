@@ -0,0 +1,268 @@
+//! Advent of Code day 12
+//! <https://adventofcode.com/2022/day/12>
+//!
+//! A pathfinding problem with weights and a few special rules on what counts as obstacle.
+//! I don't feel like re-implementing Dijkstra, so we will be lazy here.
+//!
+//! First, looking for a suitable library results in the "pathfinding" crate,
+//! which seems to be exactly what we need. In addition, there exists an article
+//! on the topic, together with a Github repo with examples making use of the
+//! "pathfinding" crate. Here, we will also snitch a few things, specifically
+//! the "Board" structure:
+//! <https://blog.logrocket.com/pathfinding-rust-tutorial-examples/>
+//! <https://github.com/gregstoll/rust-pathfinding>
+#![warn(missing_docs)]
+mod dijkstra;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    frame_stride, Answer, Frame, GenerateInput, Grid, InputRng, NoParams, Oracle, Rgb, Solution,
+    Visualize,
+};
+use pathfinding::prelude::dijkstra;
+use rand::Rng;
+
+/// Day 12: Hill Climbing Algorithm.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Params = NoParams;
+    type Input<'a> = Board;
+
+    fn parse(input: &str) -> Result<Board> {
+        Board::new(input)
+    }
+
+    fn part1(board: &Board, _params: &NoParams) -> Result<Answer> {
+        Ok(solution_part1(board)?.into())
+    }
+
+    fn part2(board: &Board, _params: &NoParams) -> Result<Answer> {
+        Ok(solution_part2(board)?.into())
+    }
+}
+
+/// `size` is the width of the height map, at least 28 so there is room to climb from 'a'
+/// to 'z' one step at a time. The terrain rises from left to right, with random pits.
+/// The row of the start has none, so there always is a way to the goal.
+impl GenerateInput for Day12 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        let width = size.max(28);
+        let height = (width / 4).max(5);
+        let start_row = rng.gen_range(0..height);
+        let mut board = String::new();
+        for y in 0..height {
+            for x in 0..width {
+                let level = (x * 27 / (width - 1)).min(25) as u8;
+                board.push(match x {
+                    0 if y == start_row => 'S',
+                    _ if y == start_row && x == width - 1 => 'E',
+                    _ if y != start_row && rng.gen_bool(0.2) => {
+                        (b'a' + rng.gen_range(0..=level)) as char
+                    }
+                    _ => (b'a' + level) as char,
+                });
+            }
+            board.push('\n');
+        }
+        board
+    }
+}
+
+/// The hand-written Dijkstra of the `dijkstra` module, with the same starts as
+/// `solution_part2`.
+impl Oracle for Day12 {
+    fn oracle(board: &Board, _params: &NoParams) -> Result<[Answer; 2]> {
+        let graph = dijkstra::step_graph(board);
+        let goal = dijkstra::node(board, board.goal);
+        let steps = |start| dijkstra::dijkstra(&graph, dijkstra::node(board, start), goal);
+        let part1 = steps(board.start).ok_or_else(|| anyhow!("No path found."))?;
+        let part2 = board
+            .start_candidates()
+            .into_iter()
+            .filter_map(steps)
+            .min()
+            .ok_or_else(|| anyhow!("Way with minimum number of steps couldn't be calculated."))?;
+        Ok([part1.into(), part2.into()])
+    }
+}
+
+/// Colour of the path in the visualisation.
+const PATH: Rgb = [220, 40, 40];
+
+/// Green lowlands up to white peaks, and the way from the start to the goal (part 1).
+impl Visualize for Day12 {
+    fn frames(board: &Board, _params: &NoParams) -> Result<Vec<Frame>> {
+        let path = board
+            .path_to_goal(board.start)
+            .ok_or_else(|| anyhow!("No path found."))?;
+        let mut map = board
+            .heights
+            .map(|&height| terrain_colour(height - START_HEIGHT));
+        let stride = frame_stride(path.len());
+        let mut frames = Vec::new();
+        for (idx, &pos) in path.iter().enumerate() {
+            if idx % stride == 0 {
+                frames.push(map.clone());
+            }
+            map[pos] = PATH;
+        }
+        frames.push(map);
+        Ok(frames)
+    }
+
+    /// The elevation letters, with "E" for the goal and "*" for the path.
+    fn glyph(colour: Rgb) -> char {
+        match (0..=GOAL_HEIGHT - START_HEIGHT).find(|&level| terrain_colour(level) == colour) {
+            Some(level) if level + START_HEIGHT == GOAL_HEIGHT => 'E',
+            Some(level) => (b'a' + level as u8) as char,
+            None => '*',
+        }
+    }
+}
+
+/// Colour of the terrain `level` steps above the start ('a').
+fn terrain_colour(level: u32) -> Rgb {
+    let level = level as u8;
+    [40 + 8 * level, 90 + 6 * level, 40 + 8 * level]
+}
+
+/// Position on the board. Tuples already have the derives required here:
+/// <https://docs.rs/pathfinding/latest/pathfinding/directed/dijkstra/fn.dijkstra.html>
+pub type Pos = (usize, usize);
+
+/// Elevation of the start ('S', same as 'a') and the goal ('E', higher than 'z').
+const START_HEIGHT: u32 = 10;
+const GOAL_HEIGHT: u32 = 36;
+
+/// Structure representing the rectangular height map or "board". Inspired by
+/// <https://github.com/gregstoll/rust-pathfinding/blob/main/src/lib.rs>,
+/// but adapted for the problem.
+#[derive(Debug)]
+pub struct Board {
+    heights: Grid<u32>,
+    start: Pos,
+    goal: Pos,
+}
+impl Board {
+    /// Read the height map, with the start marked 'S' and the goal marked 'E'.
+    pub fn new(input: &str) -> Result<Self> {
+        // Translate the chars to numbers.
+        let heights = Grid::parse(input, "a height ('a' to 'z', 'S' or 'E')", |c| match c {
+            'S' => Some(START_HEIGHT),
+            //'E' should be higher than 'z' so we need to special case this.
+            'E' => Some(GOAL_HEIGHT),
+            'a'..='z' => c.to_digit(36),
+            _ => None,
+        })?;
+        let chars: Grid<char> = input.parse()?;
+        let start = chars
+            .position(|&c| c == 'S')
+            .ok_or_else(|| anyhow!("No start ('S') on the board."))?;
+        let goal = chars
+            .position(|&c| c == 'E')
+            .ok_or_else(|| anyhow!("No goal ('E') on the board."))?;
+        Ok(Self {
+            heights,
+            start,
+            goal,
+        })
+    }
+
+    /// Position marked 'S'.
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    /// Position marked 'E'.
+    pub fn goal(&self) -> Pos {
+        self.goal
+    }
+
+    /// "Successors" (or neighbours of the evaluated grid position) with the cost of the step,
+    /// which is 1 for every step since we count steps, no matter how high they lead.
+    pub fn get_successors(&self, position: &Pos) -> Vec<(Pos, u32)> {
+        let current_height = self.heights[*position];
+        // compared to the version from https://github.com/gregstoll/rust-pathfinding/blob/main/src/lib.rs,
+        // we have to make sure steps with "height difference" > 1 are not taken. Here, we just exclude
+        // them from the list of valid successors. Alternatively, we could also just bump cost for these
+        // steps sufficiently.
+        self.heights
+            .neighbours4(*position)
+            .filter(|&pos| self.heights[pos] <= current_height + 1)
+            .map(|pos| (pos, 1))
+            .collect()
+    }
+
+    /// Where part 2 looks for a start: only the leftmost 'a' of each row is tried, like
+    /// in the first version.
+    pub fn start_candidates(&self) -> Vec<Pos> {
+        (0..self.heights.height())
+            .filter_map(|y| {
+                let x = self.heights.row(y).position(|&h| h == START_HEIGHT)?;
+                Some((x, y))
+            })
+            .collect()
+    }
+
+    /// Shortest path from `start` to the goal, both included, if there is one.
+    pub fn path_to_goal(&self, start: Pos) -> Option<Vec<Pos>> {
+        let (path, _cost) = dijkstra(&start, |p| self.get_successors(p), |p| *p == self.goal)?;
+        Some(path)
+    }
+
+    /// Number of steps of the shortest path from `start` to the goal, if there is one.
+    pub fn steps_to_goal(&self, start: Pos) -> Option<usize> {
+        // minus 1 since we have one less step than positions on board.
+        Some(self.path_to_goal(start)?.len() - 1)
+    }
+}
+
+/// Fewest steps from any square at the lowest elevation to the goal (part 2).
+pub fn solution_part2(board: &Board) -> Result<usize> {
+    board
+        .start_candidates()
+        .into_iter()
+        .filter_map(|start_pos| board.steps_to_goal(start_pos))
+        .min()
+        .ok_or_else(|| anyhow!("Way with minimum number of steps couldn't be calculated."))
+}
+
+/// Fewest steps from the start to the goal (part 1).
+pub fn solution_part1(board: &Board) -> Result<usize> {
+    board
+        .steps_to_goal(board.start)
+        .ok_or_else(|| anyhow!("No path found."))
+}
+
+/// Do we have the correct number of steps for the test input?
+#[test]
+fn find_path_on_test_input_part1() {
+    let board = Board::new(include_str!("../input_test.txt")).unwrap();
+    let steps = solution_part1(&board).unwrap();
+    assert_eq!(steps, 31) // minus 1 since we have one less step than positions on board.
+}
+
+#[test]
+fn find_path_on_test_input_part2() {
+    let board = Board::new(include_str!("../input_test.txt")).unwrap();
+    let steps_min = solution_part2(&board).unwrap();
+    assert_eq!(steps_min, 29)
+}
+
+/// The short way over the plateau of 'c's beats the long one through the valley of 'a's,
+/// even though the latter stays lower.
+#[test]
+fn find_path_with_fewest_steps_not_lowest_heights() {
+    let board = Board::new(concat!(
+        "SbccccccccccccccccccccccccccccdefghijklmnopqrstuvwxyzE\n",
+        "azzzzzzzzzzzzzzzzzzzzzzzzzzzzbzzzzzzzzzzzzzzzzzzzzzzzz\n",
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaazzzzzzzzzzzzzzzzzzzzzzzz\n",
+    ))
+    .unwrap();
+    assert_eq!(solution_part1(&board).unwrap(), 53);
+    assert_eq!(solution_part2(&board).unwrap(), 53);
+}
+
+aoc_common::example_tests!(Day12);
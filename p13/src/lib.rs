@@ -0,0 +1,213 @@
+//! Advent of Code day 13
+//! <https://adventofcode.com/2022/day/13>
+//!
+//! This was fun. One thing that was spoiled for me by previously clicking on an article
+//! about this riddle is that "serde_json" can be used to directly parse the input.
+//! (Nowadays the packets are parsed with our own combinators into "serde_json" values,
+//! which points out malformed packets more precisely.)
+//! I also needed some hints from other people's solutions to get the recursion for
+//! comparison right. Part 2 was straightforward.
+//!
+//! For this one, it will be interesting to review other solutions. I know I can implement
+//! "Ord" for the packets, so this would be interesting to try. I am also not sure if
+//! there is not a more elegant way than sticking all entries into a sorting algorithm.
+#![warn(missing_docs)]
+
+use anyhow::Result;
+use aoc_common::combinators::{
+    alt, delimited, map, parse_line, separated_list0, tag, uint, PResult, Span,
+};
+use aoc_common::{Answer, GenerateInput, InputLine, InputRng, NoParams, ParseError, Solution};
+use rand::Rng;
+use serde_json::{json, Value};
+use std::cmp::{max, Ordering};
+
+/// Day 13: Distress Signal.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Params = NoParams;
+    type Input<'a> = Vec<Value>;
+
+    fn parse(input: &str) -> Result<Vec<Value>> {
+        Ok(parse_packets(input.lines().collect())?)
+    }
+
+    fn part1(packets: &Vec<Value>, _params: &NoParams) -> Result<Answer> {
+        Ok(part1_solution(packets).into())
+    }
+
+    fn part2(packets: &Vec<Value>, _params: &NoParams) -> Result<Answer> {
+        Ok(part2_solution(packets.clone()).into())
+    }
+}
+
+/// `size` is the number of packet pairs.
+impl GenerateInput for Day13 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        let pairs = (0..size)
+            .map(|_| format!("{}\n{}\n", random_packet(rng, 0), random_packet(rng, 0)))
+            .collect::<Vec<_>>();
+        pairs.join("\n")
+    }
+}
+
+/// Parse all (non-empty) input lines into packets: lists of non-negative integers and
+/// further lists.
+pub fn parse_packets(input_data: Vec<&str>) -> Result<Vec<Value>, ParseError> {
+    InputLine::numbered(input_data)
+        .filter(|line| !line.text.is_empty())
+        .map(|line| parse_line(line, list))
+        .collect()
+}
+
+/// A list like "[1,[2,3]]".
+fn list(input: Span<'_>) -> PResult<'_, Value> {
+    let items = separated_list0(tag(","), packet);
+    map(delimited(tag("["), items, tag("]")), Value::Array)(input)
+}
+
+/// Either an integer or a list.
+fn packet(input: Span<'_>) -> PResult<'_, Value> {
+    alt((map(uint::<u64>("an integer"), Value::from), list))(input)
+}
+
+fn bubbles(packet_vec: &mut [Value]) {
+    let mut swapped;
+
+    loop {
+        swapped = false;
+
+        for packet_idx in 0..packet_vec.len() - 1 {
+            if comp_packets(&packet_vec[packet_idx], &packet_vec[packet_idx + 1])
+                == Ordering::Greater
+            {
+                packet_vec.swap(packet_idx, packet_idx + 1);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+    }
+}
+
+/// Order of two packets according to the puzzle rules, used for both parts.
+pub fn comp_packets(l_packet: &Value, r_packet: &Value) -> Ordering {
+    match (l_packet, r_packet) {
+        (Value::Number(x), Value::Number(y)) => x.as_i64().unwrap().cmp(&y.as_i64().unwrap()),
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..max(a.len(), b.len()) {
+                match (a.get(i), b.get(i)) {
+                    (None, _) => return Ordering::Less,
+                    (_, None) => return Ordering::Greater,
+                    (Some(x), Some(y)) => match comp_packets(x, y) {
+                        Ordering::Equal => {}
+                        c => return c,
+                    },
+                }
+            }
+            Ordering::Equal
+        }
+        (Value::Array(_), Value::Number(_)) => {
+            // Stick the "bare" number into an array so that after a sufficient
+            // number of recursion we end up with two numbers or vectors.
+            comp_packets(l_packet, &Value::Array(vec![r_packet.clone()]))
+        }
+        (Value::Number(_), Value::Array(_)) => {
+            comp_packets(&Value::Array(vec![l_packet.clone()]), r_packet)
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Find dividers by only considering numbers and arrays with single entries,
+/// checking recursively for the divider numbers if this is the case, and
+/// toss everything else.
+fn check_for_dividers(packet: &Value) -> bool {
+    match packet {
+        Value::Number(x) => {
+            matches!(x.as_i64().unwrap(), 2 | 6)
+        }
+        Value::Array(a) => match a.len() {
+            1 => check_for_dividers(a.first().unwrap()),
+            _ => false,
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Sum of the (1-based) indices of the pairs that are in the right order (part 1).
+pub fn part1_solution(packets: &[Value]) -> usize {
+    let mut ordered_idcs: Vec<usize> = Vec::new();
+
+    for (pair_idx, pair) in (1..).zip(packets.chunks_exact(2)) {
+        if comp_packets(&pair[0], &pair[1]) == Ordering::Less {
+            ordered_idcs.push(pair_idx);
+        }
+    }
+
+    let nr_ordered: usize = ordered_idcs.iter().sum();
+    nr_ordered
+}
+
+/// Sort all packets together with the divider packets `[[2]]` and `[[6]]`, and multiply
+/// the (1-based) indices of the dividers to get the decoder key (part 2).
+pub fn part2_solution(mut packet_vec: Vec<Value>) -> usize {
+    packet_vec.push(json!([[2]]));
+    packet_vec.push(json!([[6]]));
+    bubbles(&mut packet_vec);
+    let mut divider_idcs: Vec<usize> = Vec::new();
+    for (idx, packet) in packet_vec.iter().enumerate() {
+        if check_for_dividers(packet) {
+            divider_idcs.push(idx + 1);
+        }
+    }
+    let decoder_key: usize = divider_idcs.iter().product();
+    decoder_key
+}
+
+/// Random packet for the input generator, with lists nested up to four levels deep.
+fn random_packet(rng: &mut InputRng, depth: usize) -> String {
+    let len = rng.gen_range(0..=5);
+    let elements = (0..len)
+        .map(|_| match depth < 4 && rng.gen_bool(0.3) {
+            true => random_packet(rng, depth + 1),
+            false => rng.gen_range(0..=10).to_string(),
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", elements.join(","))
+}
+
+#[test]
+fn part1_validate_on_testdata() {
+    let input_data = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+
+    let packets = parse_packets(input_data).unwrap();
+    let nr_ordered = part1_solution(&packets);
+    assert_eq!(nr_ordered, 13);
+}
+
+#[test]
+fn part2_validate_on_testdata() {
+    let input_data = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+
+    let packets = parse_packets(input_data).unwrap();
+    let result_p2 = part2_solution(packets);
+    assert_eq!(result_p2, 140);
+}
+
+#[test]
+fn parse_packets_reports_position() {
+    let err = parse_packets(vec!["[1,2]", "", "[1,[2,]]"]).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (3, 7, "]"));
+    let err = parse_packets(vec!["[1,\"x\"]"]).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (1, 4, "\""));
+}
+
+aoc_common::example_tests!(Day13);
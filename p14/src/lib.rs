@@ -0,0 +1,302 @@
+//! Advent of Code day 14
+//! <https://adventofcode.com/2022/day/14>
+#![warn(missing_docs)]
+
+use anyhow::Result;
+use aoc_common::combinators::{
+    map, parse_line, separated_list1, separated_pair, spanned, tag, uint,
+};
+use aoc_common::{
+    frame_stride, Answer, Frame, GenerateInput, Grid, InputLine, InputRng, NoParams, ParseError,
+    Rgb, Solution, Vec2, Visualize,
+};
+use rand::Rng;
+
+/// Position of the sand source, see puzzle description.
+pub const SOURCE: (usize, usize) = (500, 0);
+
+// Grid values: empty cells are 0, rocks 255, resting sand 64, and the source 128.
+/// Grid value of rock.
+pub const ROCK: i32 = 255;
+/// Grid value of sand that came to rest.
+pub const SAND: i32 = 64;
+/// Grid value of the sand source.
+pub const SOURCE_MARK: i32 = 128;
+
+/// Day 14: Regolith Reservoir.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Params = NoParams;
+    type Input<'a> = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Vec<Point>> {
+        let stone_walls = parse_input(input.lines().collect())?;
+        Ok(build_rock_coordinates(stone_walls))
+    }
+
+    fn part1(all_wall_points: &Vec<Point>, _params: &NoParams) -> Result<Answer> {
+        let (mut map_grid, grid_offset) = initialize_grid(all_wall_points.clone(), SOURCE);
+        Ok(drop_sand(&mut map_grid, SOURCE, grid_offset).into())
+    }
+
+    fn part2(all_wall_points: &Vec<Point>, _params: &NoParams) -> Result<Answer> {
+        let all_wall_points = add_floor(all_wall_points.clone(), SOURCE);
+        let (mut map_grid, grid_offset) = initialize_grid(all_wall_points, SOURCE);
+        Ok(drop_sand(&mut map_grid, SOURCE, grid_offset).into())
+    }
+}
+
+/// `size` is the number of rock paths, at least two. They take turns starting to the
+/// left and to the right of the sand source, so the cave always lies below the source.
+impl GenerateInput for Day14 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        // small enough for the cave to stay clear of negative x coordinates
+        let spread = (10 + size as i32).min(300);
+        let source_x = SOURCE.0 as i32;
+        (0..size.max(2))
+            .map(|idx| {
+                let side = if idx % 2 == 0 { -1 } else { 1 };
+                let mut corner = Point::new(
+                    source_x + side * rng.gen_range(0..=spread),
+                    rng.gen_range(2..=spread),
+                );
+                let mut corners = vec![corner];
+                for turn in 0..rng.gen_range(1..=4) {
+                    let len =
+                        rng.gen_range(1..=spread / 3) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                    corner += if turn % 2 == 0 {
+                        Point::new(len, 0)
+                    } else {
+                        Point::new(0, len)
+                    };
+                    corner.y = corner.y.max(1); // the source is in row 0
+                    corners.push(corner);
+                }
+                let corners = corners.iter().map(|c| format!("{},{}", c.x, c.y));
+                corners.collect::<Vec<_>>().join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
+
+/// Sand piling up on the floor of part 2 until it blocks the source.
+impl Visualize for Day14 {
+    fn frames(all_wall_points: &Vec<Point>, _params: &NoParams) -> Result<Vec<Frame>> {
+        let all_wall_points = add_floor(all_wall_points.clone(), SOURCE);
+        let (mut map_grid, grid_offset) = initialize_grid(all_wall_points, SOURCE);
+        let nr_units = drop_sand(&mut map_grid.clone(), SOURCE, grid_offset);
+        let source = (SOURCE.0 - grid_offset, SOURCE.1);
+        let stride = frame_stride(nr_units);
+        let mut frames = Vec::new();
+        for unit_nr in 0..nr_units {
+            if unit_nr % stride == 0 {
+                frames.push(map_grid.map(|&cell| cell_colour(cell)));
+            }
+            drop_unit(&mut map_grid, source);
+        }
+        frames.push(map_grid.map(|&cell| cell_colour(cell)));
+        Ok(frames)
+    }
+
+    fn glyph(colour: Rgb) -> char {
+        let style = CELL_STYLES
+            .iter()
+            .find(|(_cell, style_colour, _glyph)| *style_colour == colour);
+        style.map_or('.', |(_cell, _colour, glyph)| *glyph)
+    }
+}
+
+/// Colour and glyph of each grid value in the visualisation, empty cells last.
+const CELL_STYLES: [(i32, Rgb, char); 4] = [
+    (ROCK, [110, 100, 90], '#'),
+    (SAND, [230, 190, 100], 'o'),
+    (SOURCE_MARK, [220, 40, 40], '+'),
+    (0, [16, 16, 32], '.'),
+];
+
+/// Colour of a grid value in the visualisation.
+fn cell_colour(cell: i32) -> Rgb {
+    let style = CELL_STYLES
+        .iter()
+        .find(|(style_cell, _colour, _glyph)| *style_cell == cell);
+    style.unwrap_or(&CELL_STYLES[3]).1
+}
+
+/// Point of a rock wall, x being the column and y the row (pointing down).
+pub type Point = Vec2<i32>;
+
+/// Moves a sand unit tries, in this order: straight down, down-left and down-right.
+const FALL_MOVES: [Point; 3] = [Point::DOWN, Point::new(-1, 1), Point::new(1, 1)];
+
+/// Headless version of the sandflow simulation shown in the GUI: drop sand units
+/// one by one until a unit leaves the grid (part 1) or the source is blocked (part 2),
+/// and return the number of units that came to rest.
+pub fn drop_sand(
+    map_grid: &mut Grid<i32>,
+    source_coord: (usize, usize),
+    grid_offset: usize,
+) -> usize {
+    let source = (source_coord.0 - grid_offset, source_coord.1);
+    let mut unit_nr = 0;
+    while drop_unit(map_grid, source) {
+        unit_nr += 1;
+    }
+    unit_nr
+}
+
+/// Drop a single sand unit from `source` (in grid coordinates) and mark where it comes
+/// to rest. Returns false if it falls into the abyss, or the source is blocked already.
+pub fn drop_unit(map_grid: &mut Grid<i32>, source: (usize, usize)) -> bool {
+    if map_grid[source] == SAND {
+        return false;
+    }
+    let mut unit = Point::new(source.0 as i32, source.1 as i32);
+    'falling: loop {
+        for fall_move in FALL_MOVES {
+            let next = unit + fall_move;
+            // falling off the bottom or the sides of the grid means falling into the abyss
+            if !map_grid.contains((next.x as isize, next.y as isize)) {
+                return false;
+            }
+            if map_grid[(next.x as usize, next.y as usize)] == 0 {
+                unit = next;
+                continue 'falling;
+            }
+        }
+        map_grid[(unit.x as usize, unit.y as usize)] = SAND;
+        return true;
+    }
+}
+
+/// Draw the rocks into a grid just wide enough to hold them and the source, and mark
+/// the source. Returns the grid along with the x coordinate of its first column.
+pub fn initialize_grid(
+    all_wall_points: Vec<Point>,
+    source_coord: (usize, usize),
+) -> (Grid<i32>, usize) {
+    let source = Point::new(source_coord.0 as i32, source_coord.1 as i32);
+    let points = || all_wall_points.iter().chain([&source]);
+    let x_min = points().map(|e| e.x).min().unwrap();
+    let x_max = points().map(|e| e.x).max().unwrap();
+    let y_max = points().map(|e| e.y).max().unwrap();
+
+    // initialize grid, x is the column and y the row as in the puzzle description
+    let mut map_grid = Grid::new((x_max - x_min + 1) as usize, (y_max + 1) as usize, 0);
+
+    // populate grid with stones
+    for grid_point in all_wall_points {
+        map_grid[((grid_point.x - x_min) as usize, grid_point.y as usize)] = ROCK;
+    }
+
+    let grid_offset = x_min as usize;
+    map_grid[(source_coord.0 - grid_offset, source_coord.1)] = SOURCE_MARK;
+
+    (map_grid, grid_offset)
+}
+
+/// All points covered by rock, given the corner points of each wall.
+pub fn build_rock_coordinates(stone_walls: Vec<Vec<Point>>) -> Vec<Point> {
+    let mut all_wall_points: Vec<Point> = Vec::new();
+    for current_wall in stone_walls {
+        for diff_idx in 1..current_wall.len() {
+            let (start, end) = (current_wall[diff_idx - 1], current_wall[diff_idx]);
+            all_wall_points.push(start);
+
+            // walls are horizontal or vertical (see "parse_input"), so they are as long
+            // as the Manhattan distance
+            let step = (end - start).signum();
+            for d_idx in 1..start.manhattan(end) {
+                all_wall_points.push(start + step * d_idx);
+            }
+        }
+        all_wall_points.push(current_wall.last().unwrap().to_owned());
+    }
+
+    all_wall_points
+}
+
+/// Add the floor for part 2, two units below the lowest rock. Sand can pile up at
+/// most as far sideways as it falls, so the floor doesn't need to be wider than that.
+pub fn add_floor(mut all_wall_points: Vec<Point>, source_coord: (usize, usize)) -> Vec<Point> {
+    let source = Point::new(source_coord.0 as i32, source_coord.1 as i32);
+    let points = || all_wall_points.iter().chain([&source]);
+    let y_max = points().map(|e| e.y).max().unwrap();
+    let x_min = points().map(|e| e.x).min().unwrap();
+    let x_max = points().map(|e| e.x).max().unwrap();
+
+    let y_floor = y_max + 2;
+    let source_x = source.x;
+    let x_floor_min = x_min.min(source_x - y_floor - 1);
+    let x_floor_max = x_max.max(source_x + y_floor + 1);
+
+    for x_floor in x_floor_min..=x_floor_max {
+        all_wall_points.push(Point {
+            x: x_floor,
+            y: y_floor,
+        });
+    }
+
+    all_wall_points
+}
+
+/// Parse lines like "498,4 -> 498,6 -> 496,6" into the corner points of each wall.
+/// Coordinates can't be negative, walls run horizontally or vertically from corner to
+/// corner, and there has to be at least one.
+pub fn parse_input(lines: Vec<&str>) -> Result<Vec<Vec<Point>>, ParseError> {
+    let point = map(
+        separated_pair(uint("an x coordinate"), tag(","), uint("a y coordinate")),
+        |(x, y)| Point { x, y },
+    );
+    let path = separated_list1(tag(" -> "), spanned(point));
+    let mut stone_walls = Vec::new();
+    for line in InputLine::numbered(lines) {
+        let corners = parse_line(line, &path)?;
+        for pair in corners.windows(2) {
+            let ((_, start), (token, end)) = (pair[0], pair[1]);
+            if start.x != end.x && start.y != end.y {
+                return Err(line.error_at(
+                    token,
+                    format!("a corner in line with {},{}", start.x, start.y),
+                ));
+            }
+        }
+        stone_walls.push(corners.into_iter().map(|(_, corner)| corner).collect());
+    }
+    if stone_walls.is_empty() {
+        return Err(InputLine::new(1, "").error("a rock path"));
+    }
+    Ok(stone_walls)
+}
+
+/// Rocks only to one side of the source still make a grid holding the source.
+#[test]
+fn rocks_right_of_source() {
+    let rocks = Day14::parse("510,5 -> 512,5").unwrap();
+    assert_eq!(
+        Day14::part1(&rocks, &NoParams::default()).unwrap(),
+        Answer::Number(0)
+    );
+    // the sand piles up to the source from the floor at y = 7, missing the rocks
+    assert_eq!(
+        Day14::part2(&rocks, &NoParams::default()).unwrap(),
+        Answer::Number(49)
+    );
+}
+
+/// Input the cave can't be built from is rejected, pointing at the culprit.
+#[test]
+fn invalid_rock_paths() {
+    let err = parse_input(vec![]).unwrap_err();
+    assert_eq!((err.line, err.expected.as_str()), (1, "a rock path"));
+
+    let err = parse_input(vec!["498,-4 -> 498,6"]).unwrap_err();
+    assert_eq!((err.column, err.found.as_str()), (5, "-"));
+
+    let err = parse_input(vec!["498,4 -> 498,6", "503,4 -> 502,5"]).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (2, 10, "502,5"));
+    assert_eq!(err.expected, "a corner in line with 503,4");
+}
+
+aoc_common::example_tests!(Day14);
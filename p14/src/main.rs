@@ -3,11 +3,13 @@
 mod matplotlib_cmaps;
 
 use std::fmt;
-use std::ops::Sub;
 
 use anyhow::Result;
+use aoc_common::{read_input, Grid};
 use eframe::{egui, epaint::vec2};
 use egui_plot::{Plot, PlotImage, PlotPoint};
+use p14::{add_floor, build_rock_coordinates, initialize_grid, parse_input, SOURCE};
+use tracing::error;
 
 use crate::matplotlib_cmaps::{
     BLUERED_DATA, CIVIDIS_DATA, HELL_DATA, INFERNO_DATA, MAGMA_DATA, PLASMA_DATA, TURBO_DATA,
@@ -15,6 +17,7 @@ use crate::matplotlib_cmaps::{
 };
 
 /// Enum for colormap selection.
+#[allow(dead_code)] // only Magma is used so far, there is no colormap selection in the GUI yet
 #[derive(PartialEq, Clone)]
 enum CMaps {
     RdBu,
@@ -45,8 +48,8 @@ impl fmt::Display for CMaps {
 
 #[derive(Default)]
 struct P14Gui {
-    map_grid_initial: Vec<Vec<i32>>,
-    map_grid_current: Vec<Vec<i32>>,
+    map_grid_initial: Grid<i32>,
+    map_grid_current: Grid<i32>,
     source: (usize, usize),
     grid_offset: usize,
     cur_unit_pos: (usize, usize),
@@ -60,9 +63,9 @@ struct P14Gui {
 impl P14Gui {
     /// Build new texture if necessary, and upload to GPU memory.
     fn build_texture(&mut self, ctx: &egui::Context) {
-        let curr_grid: &Vec<Vec<i32>> = self.map_grid_current.as_ref();
+        let curr_grid: &Grid<i32> = &self.map_grid_current;
 
-        let shape = (curr_grid.len(), curr_grid[0].len());
+        let shape = (curr_grid.width(), curr_grid.height());
 
         let size_img: [usize; 2] = [shape.0, shape.1];
         if self.cmap.is_none() {
@@ -85,7 +88,7 @@ impl P14Gui {
 
         // single-threaded colormapping
         for (x, y, pixel) in imgbuf.enumerate_pixels_mut() {
-            let col_val = curr_grid[x as usize][y as usize] as usize;
+            let col_val = curr_grid[(x as usize, y as usize)] as usize;
             *pixel = image::Rgb([
                 (grad[col_val][0] * 255.0) as u8,
                 (grad[col_val][1] * 255.0) as u8,
@@ -109,8 +112,8 @@ impl P14Gui {
         self.build_texture(ui.ctx());
 
         let size_img: [f32; 2] = [
-            self.map_grid_initial.len() as f32,
-            self.map_grid_initial[0].len() as f32,
+            self.map_grid_initial.width() as f32,
+            self.map_grid_initial.height() as f32,
         ];
 
         let tex = self.texture.clone().unwrap();
@@ -133,16 +136,16 @@ impl P14Gui {
             self.cur_unit_pos = (self.source.0 - self.grid_offset, self.source.1);
         } else {
             let (x_prev, y_prev) = self.cur_unit_pos;
-            if y_prev >= self.map_grid_current[0].len() - 1 {
+            if y_prev >= self.map_grid_current.height() - 1 {
                 return;
             }
             let (x_new, y_new) = (self.cur_unit_pos.0, self.cur_unit_pos.1 + 1);
-            match self.map_grid_current[x_new][y_new] {
+            match self.map_grid_current[(x_new, y_new)] {
                 0 => {
                     self.cur_unit_pos = (x_new, y_new);
-                    self.map_grid_current[x_new][y_new] = 64;
-                    if self.map_grid_current[x_prev][y_prev] != 128 {
-                        self.map_grid_current[x_prev][y_prev] = 0;
+                    self.map_grid_current[(x_new, y_new)] = 64;
+                    if self.map_grid_current[(x_prev, y_prev)] != 128 {
+                        self.map_grid_current[(x_prev, y_prev)] = 0;
                     }
                 }
                 64 | 255 => {
@@ -150,28 +153,28 @@ impl P14Gui {
                         return;
                     }
                     let x_new_l = x_new - 1;
-                    match self.map_grid_current[x_new_l][y_new] {
+                    match self.map_grid_current[(x_new_l, y_new)] {
                         0 => {
                             self.cur_unit_pos = (x_new_l, y_new);
-                            self.map_grid_current[x_new_l][y_new] = 64;
-                            if self.map_grid_current[x_prev][y_prev] != 128 {
-                                self.map_grid_current[x_prev][y_prev] = 0;
+                            self.map_grid_current[(x_new_l, y_new)] = 64;
+                            if self.map_grid_current[(x_prev, y_prev)] != 128 {
+                                self.map_grid_current[(x_prev, y_prev)] = 0;
                             }
                         }
                         64 | 255 => {
-                            if x_new == self.map_grid_current.len() {
+                            if x_new == self.map_grid_current.width() {
                                 return;
                             }
                             let x_new_r = x_new + 1;
-                            match self.map_grid_current[x_new_r][y_new] {
+                            match self.map_grid_current[(x_new_r, y_new)] {
                                 0 => {
                                     self.cur_unit_pos = (x_new_r, y_new);
-                                    self.map_grid_current[x_new_r][y_new] = 64;
-                                    if self.map_grid_current[x_prev][y_prev] != 128 {
-                                        self.map_grid_current[x_prev][y_prev] = 0;
+                                    self.map_grid_current[(x_new_r, y_new)] = 64;
+                                    if self.map_grid_current[(x_prev, y_prev)] != 128 {
+                                        self.map_grid_current[(x_prev, y_prev)] = 0;
                                     }
                                 }
-                                64 | 255 => match self.map_grid_current[x_prev][y_prev] {
+                                64 | 255 => match self.map_grid_current[(x_prev, y_prev)] {
                                     128 => {}
                                     _ => {
                                         self.cur_unit_pos = (0, 0);
@@ -203,35 +206,17 @@ impl eframe::App for P14Gui {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-impl Sub for Point {
-    type Output = Self;
-
-    fn sub(self, other: Self) -> Self::Output {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
-    }
-}
-
 fn main() -> Result<()> {
-    // let lines = include_str!("../input_test.txt")
-    //     .lines()
-    //     .collect::<Vec<_>>();
-    let lines = include_str!("../input.txt").lines().collect::<Vec<_>>();
-    let source = (500, 0);
+    let input = read_input(env!("CARGO_MANIFEST_DIR"))?;
+    let lines = input.lines().collect::<Vec<_>>();
+    let source = SOURCE;
 
-    let stone_walls = parse_input(lines);
-    let all_wall_points = build_rock_coordinates(stone_walls);
+    let stone_walls = parse_input(lines)?;
+    let all_wall_points = add_floor(build_rock_coordinates(stone_walls), source);
     let (map_grid_initial, grid_offset) = initialize_grid(all_wall_points, source);
 
     // fire up GUI
-    let mut gui_state = P14Gui {
+    let gui_state = P14Gui {
         map_grid_initial: map_grid_initial.clone(),
         map_grid_current: map_grid_initial,
         source,
@@ -264,8 +249,8 @@ fn main() -> Result<()> {
 
     match gui {
         Ok(_res) => {}
-        Err(_res) => {
-            println!("Error executing GUI thread.")
+        Err(err) => {
+            error!(%err, "error executing GUI thread");
         }
     };
 
@@ -279,101 +264,3 @@ fn main() -> Result<()> {
 
     Ok(())
 }
-
-fn initialize_grid(
-    all_wall_points: Vec<Point>,
-    source_coord: (usize, usize),
-) -> (Vec<Vec<i32>>, usize) {
-    let x_min = all_wall_points.clone().iter().map(|e| e.x).min().unwrap();
-    let x_max = all_wall_points.clone().iter().map(|e| e.x).max().unwrap();
-    let y_max = all_wall_points.clone().iter().map(|e| e.y).max().unwrap();
-
-    // initialize grid (ndarray would be better):
-    let mut map_grid = Vec::new();
-    for _x_idx in x_min..=x_max {
-        map_grid.push(vec![0; (y_max + 1) as usize]);
-    }
-
-    // populate grid with stones
-    for grid_point in all_wall_points {
-        map_grid[(grid_point.x - x_min) as usize][grid_point.y as usize] = 255;
-    }
-
-    let grid_offset = x_min as usize;
-    map_grid[source_coord.0 - grid_offset][source_coord.1] = 128;
-
-    (map_grid, grid_offset)
-}
-
-fn build_rock_coordinates(stone_walls: Vec<Vec<Point>>) -> Vec<Point> {
-    let mut all_wall_points: Vec<Point> = Vec::new();
-    for current_wall in stone_walls {
-        for diff_idx in 1..current_wall.len() {
-            let p_diff = current_wall[diff_idx] - current_wall[diff_idx - 1];
-
-            all_wall_points.push(current_wall[diff_idx - 1]);
-
-            if p_diff.x == 0 {
-                for d_idx in 0..p_diff.y.abs() {
-                    if d_idx > 0 {
-                        all_wall_points.push(Point {
-                            x: current_wall[diff_idx - 1].x,
-                            y: current_wall[diff_idx - 1].y + d_idx * p_diff.y.signum(),
-                        });
-                    }
-                }
-            } else if p_diff.y == 0 {
-                for d_idx in 0..p_diff.x.abs() {
-                    if d_idx > 0 {
-                        all_wall_points.push(Point {
-                            x: current_wall[diff_idx - 1].x + d_idx * p_diff.x.signum(),
-                            y: current_wall[diff_idx - 1].y,
-                        });
-                    }
-                }
-            }
-        }
-        all_wall_points.push(current_wall.last().unwrap().to_owned());
-    }
-
-    let y_max = all_wall_points.clone().iter().map(|e| e.y).max().unwrap();
-    let x_min = all_wall_points.clone().iter().map(|e| e.x).min().unwrap();
-    let x_max = all_wall_points.clone().iter().map(|e| e.x).max().unwrap();
-
-    for x_floor in (x_min - 200)..(x_max + 200) {
-        all_wall_points.push(Point {
-            x: x_floor,
-            y: y_max + 2,
-        });
-    }
-
-    all_wall_points
-}
-
-fn parse_input(lines: Vec<&str>) -> Vec<Vec<Point>> {
-    let mut stone_walls = Vec::new();
-
-    for line in lines {
-        let mut stone_wall: Vec<Point> = Vec::new();
-        let coords = line.split(" -> ").collect::<Vec<_>>();
-        for coord in coords {
-            let split_coords = coord.split(',').collect::<Vec<_>>();
-            stone_wall.push(Point {
-                x: split_coords[0].parse::<i32>().unwrap(),
-                y: split_coords[1].parse::<i32>().unwrap(),
-            });
-        }
-        stone_walls.push(stone_wall);
-    }
-    stone_walls
-}
-
-#[test]
-fn part1_validate_on_testdata() {
-    unimplemented!();
-}
-
-#[test]
-fn part2_validate_on_testdata() {
-    unimplemented!();
-}
@@ -6,7 +6,7 @@
 //! link to the easiest reference for this data is
 //! https://github.com/BIDS/colormap/blob/master/colormaps.py for the data itself, or
 //! http://bids.github.io/colormap/ for the general idea.
-#[allow(dead_code)]
+#![allow(dead_code, clippy::approx_constant)]
 
 pub const MAGMA_DATA: [[f32; 3]; 256] = [
     [0.001462, 0.000466, 0.013866],
@@ -0,0 +1,325 @@
+//! Advent of Code day 15
+//! <https://adventofcode.com/2022/day/15>
+//!
+//! This was interesting, and I had no issues to find a solution for part 1 that was in principle right,
+//! but I had a hard time debugging off-by-one errors for ranges. Started out with a grid, which
+//! worked fine for the test data, but fell flat for the real data due to the grid size.
+//! Changed the approach to end up with a slow but fast enough solution.
+//!
+//! Similar story for part 2: Brute force approach worked fine for test data, but was hopeless
+//! for the real input data. At this point I already had stumbled upon some spoilers on how other
+//! people solved this, so I went with the simplest approach: checking if there are any spots at
+//! sensor-beacon distance + 1. This is fairly slow (37s) but at this point I just I am very much
+//! over this day's puzzle and want to get on, so: neither optimization nor cleanup of the code :-)
+#![warn(missing_docs)]
+
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+use aoc_common::combinators::{map, pair, parse_lines, preceded, tag, xy};
+use aoc_common::{Answer, GenerateInput, InputRng, Oracle, ParseError, Solution, Vec2};
+use rand::Rng;
+use serde::Deserialize;
+use tracing::{debug, trace};
+
+/// Day 15: Beacon Exclusion Zone.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Params = Params;
+    type Input<'a> = (Vec<Point>, Vec<Point>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input.lines().collect())?)
+    }
+
+    fn part1((sensors, beacons): &Self::Input<'_>, params: &Params) -> Result<Answer> {
+        Ok(excluded_positions(sensors.clone(), beacons.clone(), params.row).into())
+    }
+
+    fn part2((sensors, beacons): &Self::Input<'_>, params: &Params) -> Result<Answer> {
+        let bounds = (0, params.max_coordinate);
+        let candidates = border_points(sensors.clone(), beacons.clone(), bounds);
+        let remaining_candidates = beacon_candidates(candidates, sensors.clone(), beacons.clone());
+        match remaining_candidates[..] {
+            [beacon] => Ok(tuning_frequency(beacon).into()),
+            [] => bail!("No position for the distress beacon found."),
+            _ => bail!(
+                "Found an ambiguous distress beacon position: {} candidates remain.",
+                remaining_candidates.len()
+            ),
+        }
+    }
+}
+
+/// `size` is the number of sensors (at least one), fewer if they don't fit into a small
+/// search area. All of them just miss the distress beacon, so there always is a position
+/// for it. More sensors fill the gaps left over besides it, so it's the only one.
+impl GenerateInput for Day15 {
+    fn generate_input(rng: &mut InputRng, size: usize, params: &Params) -> String {
+        let max = params.max_coordinate.max(2);
+        let distress_beacon = Point::new(rng.gen_range(0..=max), rng.gen_range(0..=max));
+        let mut reports: Vec<(Point, Point)> = Vec::new();
+        let size = size.max(1);
+        for _attempt in 0..size * 100 {
+            if reports.len() == size {
+                break;
+            }
+            let sensor = Point::new(rng.gen_range(0..=max), rng.gen_range(0..=max));
+            let range = sensor.manhattan(distress_beacon) - 1;
+            if range < 1 {
+                continue;
+            }
+            // somewhere on the border of the sensor's range
+            let dx = rng.gen_range(-range..=range);
+            let dy = (range - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+            let beacon = sensor + Point::new(dx, dy);
+            // each sensor reports the beacon closest to it, so no other may be closer
+            let closest = |(sensor, beacon): (Point, Point), other: Point| {
+                other == beacon || sensor.manhattan(other) > sensor.manhattan(beacon)
+            };
+            if reports.iter().all(|&(other_sensor, other_beacon)| {
+                closest((other_sensor, other_beacon), beacon)
+                    && closest((sensor, beacon), other_beacon)
+            }) {
+                reports.push((sensor, beacon));
+            }
+        }
+        // close the gaps part 2 would find besides the distress beacon with sensors on them,
+        // which report the closest beacon so far unless that is farther than the distress
+        // beacon, and a beacon of their own otherwise
+        loop {
+            let (sensors, beacons): (Vec<Point>, Vec<Point>) = reports.iter().copied().unzip();
+            let candidates = border_points(sensors.clone(), beacons.clone(), (0, max));
+            let mut gaps = beacon_candidates(candidates, sensors, beacons)
+                .into_iter()
+                .filter(|&gap| gap != distress_beacon)
+                .collect::<Vec<_>>();
+            if gaps.is_empty() {
+                break;
+            }
+            gaps.sort_by_key(|gap| (gap.x, gap.y));
+            for gap in gaps {
+                let covered = reports
+                    .iter()
+                    .any(|(sensor, beacon)| sensor.manhattan(gap) <= sensor.manhattan(*beacon));
+                if covered {
+                    continue;
+                }
+                let beacon = reports
+                    .iter()
+                    .map(|&(_sensor, beacon)| beacon)
+                    .min_by_key(|beacon| beacon.manhattan(gap))
+                    .filter(|beacon| beacon.manhattan(gap) < distress_beacon.manhattan(gap))
+                    .unwrap_or(gap);
+                reports.push((gap, beacon));
+            }
+        }
+        reports
+            .iter()
+            .map(|(sensor, beacon)| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sensor.x, sensor.y, beacon.x, beacon.y
+                )
+            })
+            .collect()
+    }
+}
+
+/// The brute force `beacon_position`, row by row, which is only feasible for a small
+/// search area. Part 2 is only answered if there is exactly one position for the distress
+/// beacon, as the puzzle promises; part 1 isn't covered.
+impl Oracle for Day15 {
+    fn oracle((sensors, beacons): &Self::Input<'_>, params: &Params) -> Result<[Answer; 2]> {
+        let positions = (0..=params.max_coordinate)
+            .filter_map(|row| {
+                beacon_position(
+                    sensors.clone(),
+                    beacons.clone(),
+                    row,
+                    params.max_coordinate + 1,
+                )
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        let part2 = match positions[..] {
+            [beacon] => tuning_frequency(beacon).into(),
+            _ => Answer::Unsolved,
+        };
+        Ok([Answer::Unsolved, part2])
+    }
+}
+
+/// Puzzle parameters, see `[day15]` in aoc.toml. The example of the puzzle description
+/// uses different values than the actual puzzle.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Row in which to count the positions where the beacon can't be (part 1).
+    pub row: i32,
+    /// Largest x and y coordinate the distress beacon can have (part 2).
+    pub max_coordinate: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            max_coordinate: 4000000,
+        }
+    }
+}
+
+/// Sensor or beacon position.
+pub type Point = Vec2<i32>;
+
+/// The obligatory parser.
+pub fn parse_input(lines: Vec<&str>) -> Result<(Vec<Point>, Vec<Point>), ParseError> {
+    let point = || map(xy(), |(x, y)| Point { x, y });
+    let report = pair(
+        preceded(tag("Sensor at "), point()),
+        preceded(tag(": closest beacon is at "), point()),
+    );
+    Ok(parse_lines(lines, report)?.into_iter().unzip())
+}
+
+/// Solution for part 1; Check for points in within sensor range within specified row.
+pub fn excluded_positions(sensors: Vec<Point>, beacons: Vec<Point>, line_idx: i32) -> i32 {
+    let mut ex_pos = HashSet::new();
+
+    for (idx, sensor) in sensors.iter().enumerate() {
+        let closest_beac_dist = sensor.manhattan(beacons[idx]);
+        let x_range = (sensor.x - closest_beac_dist)..(sensor.x + closest_beac_dist);
+        for x_idx in x_range {
+            let candidate = Point {
+                x: x_idx,
+                y: line_idx,
+            };
+            if (candidate.manhattan(*sensor) <= closest_beac_dist)
+                && !(beacons.contains(&candidate))
+            {
+                ex_pos.insert(candidate);
+            }
+        }
+    }
+    ex_pos.len() as i32
+}
+
+/// Brute force approach to part 2; only used in a test case and as oracle since hopeless
+/// for real input data.
+pub fn beacon_position(
+    sensors: Vec<Point>,
+    beacons: Vec<Point>,
+    line_idx: i32,
+    x_max: i32,
+) -> Option<Vec<Point>> {
+    let mut all_pos = HashSet::new();
+    let mut ex_pos = HashSet::new();
+    // let mut beac_pos: Option<Vec<Point>> = None;
+
+    for (idx, sensor) in sensors.iter().enumerate() {
+        let closest_beac_dist = sensor.manhattan(beacons[idx]);
+        for x_idx in 0..x_max {
+            let candidate = Point {
+                x: x_idx,
+                y: line_idx,
+            };
+            if !(beacons.contains(&candidate)) {
+                all_pos.insert(candidate);
+            }
+            if (candidate.manhattan(*sensor) <= closest_beac_dist)
+                && !(beacons.contains(&candidate))
+            {
+                trace!(?candidate, "excluded");
+                ex_pos.insert(candidate);
+            }
+        }
+    }
+    let beac_pos_set = all_pos.difference(&ex_pos);
+    Some(beac_pos_set.into_iter().copied().collect())
+}
+
+/// Get points just outside sensor range.
+pub fn border_points(
+    sensors: Vec<Point>,
+    beacons: Vec<Point>,
+    gridsize: (i32, i32),
+) -> HashSet<Point> {
+    let mut candidates = HashSet::new();
+
+    for (idx, sensor) in sensors.iter().enumerate() {
+        let border = sensor.manhattan(beacons[idx]);
+        let outside_border = border + 1;
+
+        // walk along one edge of the diamond just outside the sensor range, and
+        // rotate the offset from the sensor to get the three other edges
+        for rp_idx in 0..outside_border {
+            let mut offset = Point::new(outside_border - rp_idx, rp_idx);
+            for _edge in 0..4 {
+                check_borders_and_add(*sensor + offset, &mut candidates, gridsize);
+                offset = offset.rotate_cw();
+            }
+        }
+    }
+
+    debug!(nr_candidates = candidates.len(), "border points");
+    candidates
+}
+
+/// Helper function to get rid of candidates outside the specified grid size.
+fn check_borders_and_add(candidate: Point, candidates: &mut HashSet<Point>, gridsize: (i32, i32)) {
+    if (candidate.x >= gridsize.0)
+        && (candidate.x <= gridsize.1)
+        && (candidate.y >= gridsize.0)
+        && (candidate.y <= gridsize.1)
+    {
+        candidates.insert(candidate);
+    }
+}
+
+/// Checks if "candidates" (points just one distance unit outside sensor range
+/// as provided by "border_points") are within range of any other sensor, and
+/// returns any identified out-of-range points.
+pub fn beacon_candidates(
+    candidates: HashSet<Point>,
+    sensors: Vec<Point>,
+    beacons: Vec<Point>,
+) -> Vec<Point> {
+    let mut excluded_candidates: HashSet<Point> = HashSet::new();
+
+    for candidate in &candidates {
+        for (idx, sensor) in sensors.iter().enumerate() {
+            if sensor.manhattan(*candidate) <= sensor.manhattan(beacons[idx]) {
+                excluded_candidates.insert(*candidate);
+            }
+        }
+    }
+
+    let remaining_candidates = candidates
+        .difference(&excluded_candidates)
+        .cloned()
+        .collect::<Vec<_>>();
+    remaining_candidates
+}
+
+/// Calculate tuning frequency for part 2 solution.
+pub fn tuning_frequency(beacon_candidate: Point) -> u64 {
+    beacon_candidate.x as u64 * 4000000 + beacon_candidate.y as u64
+}
+
+#[test]
+fn ambiguous_beacon_position() {
+    let input = "Sensor at x=5, y=5: closest beacon is at x=5, y=6\n";
+    let params = Params {
+        max_coordinate: 20,
+        ..Params::default()
+    };
+    let err = Day15::part2(&Day15::parse(input).unwrap(), &params).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("ambiguous distress beacon position"));
+}
+
+aoc_common::example_tests!(Day15);
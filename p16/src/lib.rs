@@ -0,0 +1,430 @@
+//! Advent of Code day 16
+//! <https://adventofcode.com/2022/day/16>
+//! In case I refactor this into struct/impl I can untangle this very procedural piece of code :-)
+#![warn(missing_docs)]
+mod montecarlo;
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs::File;
+use std::hash::Hash;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::combinators::{
+    alt, comma_list, pair, parse_line, preceded, tag, take_while1, uint,
+};
+use aoc_common::{Answer, GenerateInput, InputLine, InputRng, Oracle, ParseError, Solution};
+use petgraph::algo::floyd_warshall;
+use petgraph::dot::{Config, Dot};
+use petgraph::graph::{Graph, NodeIndex, UnGraph};
+use petgraph::prelude::*;
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+use rayon::prelude::*;
+use serde::Deserialize;
+
+/// Day 16: Proboscidea Volcanium.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Params = Params;
+    type Input<'a> = (ValveGraph, HashMap<String, NodeIndex>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        build_graph(parse_input(input.lines().collect())?)
+    }
+
+    fn part1((graph, node_index_map): &Self::Input<'_>, params: &Params) -> Result<Answer> {
+        let start_node = idx_by_name(node_index_map, &params.start_valve)?;
+        let pressures = evaluate_population(graph, start_node, params.minutes, params.population);
+        Ok(pressures.into_iter().max().unwrap_or(0).into())
+    }
+
+    fn part2(_input: &Self::Input<'_>, _params: &Params) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+/// `size` is the number of valves, about a quarter of which have a flow rate. The start
+/// valve is one of them, and all valves can be reached from it.
+impl GenerateInput for Day16 {
+    fn generate_input(rng: &mut InputRng, size: usize, params: &Params) -> String {
+        let mut names = ('A'..='Z')
+            .flat_map(|first| ('A'..='Z').map(move |second| format!("{first}{second}")))
+            .filter(|name| *name != params.start_valve)
+            .collect::<Vec<_>>();
+        names.shuffle(rng);
+        names.truncate(size.clamp(2, 676) - 1);
+        names.insert(0, params.start_valve.clone());
+
+        // random tree, so all valves are connected, plus a few more tunnels
+        let mut tunnels = vec![BTreeSet::new(); names.len()];
+        for valve in 1..names.len() {
+            let other = rng.gen_range(0..valve);
+            tunnels[valve].insert(other);
+            tunnels[other].insert(valve);
+        }
+        for _tunnel in 0..names.len() / 2 {
+            let (valve, other) = (rng.gen_range(0..names.len()), rng.gen_range(0..names.len()));
+            if valve != other {
+                tunnels[valve].insert(other);
+                tunnels[other].insert(valve);
+            }
+        }
+
+        let mut lines = (0..names.len())
+            .map(|valve| {
+                let flowrate = match valve {
+                    1.. if rng.gen_bool(0.25) => rng.gen_range(1..=25),
+                    _ => 0,
+                };
+                let connections = tunnels[valve]
+                    .iter()
+                    .map(|&other| names[other].as_str())
+                    .collect::<Vec<_>>();
+                let tunnel_text = match connections.len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+                format!(
+                    "Valve {} has flow rate={flowrate}; {tunnel_text} {}\n",
+                    names[valve],
+                    connections.join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+        lines.shuffle(rng);
+        lines.concat()
+    }
+}
+
+/// Number of random walks the oracle takes.
+const RANDOM_WALKS: usize = 100_000;
+
+/// Best of many random walks of the first attempt in the `montecarlo` module. Like the
+/// genetic algorithm this is a heuristic, so the two only agree if both find the best way,
+/// which is likely for small inputs. Part 2 isn't solved yet.
+impl Oracle for Day16 {
+    fn oracle((graph, node_index_map): &Self::Input<'_>, params: &Params) -> Result<[Answer; 2]> {
+        let pressures = (0..RANDOM_WALKS)
+            .into_par_iter()
+            .map(|_walk| {
+                montecarlo::valvewalk_mc(
+                    graph.clone(),
+                    node_index_map,
+                    &params.start_valve,
+                    params.minutes,
+                )
+                .map(|(pressure, _path)| pressure)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let part1 = pressures.into_iter().max().unwrap_or(0);
+        Ok([part1.into(), Answer::Unsolved])
+    }
+}
+
+/// Puzzle parameters, see `[day16]` in aoc.toml.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Minutes until the volcano erupts.
+    pub minutes: u32,
+    /// Valve we start at.
+    pub start_valve: String,
+    /// Population size of the genetic algorithm.
+    pub population: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            minutes: 30,
+            start_valve: "AA".to_owned(),
+            population: 10000,
+        }
+    }
+}
+
+/// Undirected graph of the valves, connected by tunnels.
+pub type ValveGraph = Graph<Valve, u32, Undirected>;
+
+/// A valve as described by one input line, along with whether it has been opened.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Valve {
+    valvename: String,
+    flowrate: u32,
+    connections: String,
+    valve_open: bool,
+}
+
+/// The obligatory parser. Tunnels can only lead to valves that are described as well.
+pub fn parse_input(lines: Vec<&str>) -> Result<Vec<Valve>, ParseError> {
+    let name = || take_while1(|ch| ch.is_ascii_uppercase(), "a valve name");
+    // "tunnel leads to valve" for a single connection, plural otherwise
+    let tunnels = alt((
+        tag("; tunnels lead to valves "),
+        tag("; tunnel leads to valve "),
+    ));
+    let valve = pair(
+        preceded(tag("Valve "), name()),
+        pair(
+            preceded(tag(" has flow rate="), uint("a flow rate")),
+            preceded(tunnels, comma_list(name())),
+        ),
+    );
+    let lines = InputLine::numbered(lines).collect::<Vec<_>>();
+    let mut valves = Vec::new();
+    let mut connections = Vec::new();
+    for &line in &lines {
+        let (valvename, (flowrate, line_connections)) = parse_line(line, &valve)?;
+        valves.push(Valve {
+            valvename: valvename.to_owned(),
+            flowrate,
+            connections: line_connections.join(", "),
+            valve_open: false,
+        });
+        connections.push((line, line_connections));
+    }
+
+    let names = valves
+        .iter()
+        .map(|valve| valve.valvename.as_str())
+        .collect::<HashSet<_>>();
+    for (line, line_connections) in connections {
+        if let Some(unknown) = line_connections.iter().find(|name| !names.contains(*name)) {
+            return Err(line.error_at(unknown, "the name of a valve in the input"));
+        }
+    }
+    Ok(valves)
+}
+
+/// Node of the valve with the given name.
+pub fn idx_by_name(
+    node_index_map: &HashMap<String, NodeIndex>,
+    valve_name: &str,
+) -> Result<NodeIndex> {
+    node_index_map
+        .get(valve_name)
+        .copied()
+        .ok_or_else(|| anyhow!("There is no valve named {valve_name:?}."))
+}
+
+/// Write the graph to `path` in the DOT format, for a look with Graphviz.
+pub fn export_graph(graph: &ValveGraph, path: &Path) -> Result<()> {
+    let graphviz_rep = format!("{:?}", Dot::with_config(&graph, &[Config::EdgeNoLabel]));
+    let mut file_handle =
+        File::create(path).with_context(|| format!("Couldn't create {}", path.display()))?;
+    file_handle.write_all(graphviz_rep.as_bytes())?;
+    Ok(())
+}
+
+/// Connect the valves by their tunnels, each taking one minute. Also returns the node
+/// of each valve by name.
+pub fn build_graph(valves: Vec<Valve>) -> Result<(ValveGraph, HashMap<String, NodeIndex>)> {
+    let mut graph: UnGraph<Valve, u32, _> = UnGraph::new_undirected();
+
+    for valve in valves {
+        graph.add_node(valve);
+    }
+
+    let node_index_map: HashMap<String, NodeIndex> = graph
+        .node_indices()
+        .map(|node_idx| (graph[node_idx].valvename.clone(), node_idx))
+        .collect();
+
+    for node_index in 0..graph.node_count() {
+        let node_idx = NodeIndex::new(node_index);
+        let conn_string = graph[node_idx].connections.clone();
+        let conns = conn_string.split(", ").collect::<Vec<_>>();
+
+        for destination in conns.iter() {
+            graph.add_edge(node_idx, idx_by_name(&node_index_map, destination)?, 1);
+        }
+    }
+
+    Ok((graph, node_index_map))
+}
+
+/// Return maximum available flow rate (corresponding to valves not already opened)
+/// used for weighting of coin flip to open valve or not
+pub fn get_max_flowrate(graph: &ValveGraph) -> u32 {
+    let mut max_flowrate = 0;
+    for rate_idx in graph.node_indices() {
+        max_flowrate = if (graph[rate_idx].flowrate > max_flowrate) && !graph[rate_idx].valve_open {
+            graph[rate_idx].flowrate
+        } else {
+            max_flowrate
+        };
+    }
+    max_flowrate
+}
+
+// fn node_by_name(
+//     node_index_map: &HashMap<String, NodeIndex>,
+//     valve_name: &str,
+//     graph: &Graph<Valve, u32, Undirected>,
+// ) -> Option<Valve> {
+//     node_index_map
+//         .get(valve_name)
+//         .map(|idx| graph[*idx].clone())
+// }
+
+/// Evaluate a randomly generated population of valve orders, returning the released
+/// pressure for each population member.
+pub fn evaluate_population(
+    graph: &ValveGraph,
+    start_node: NodeIndex,
+    minutes: u32,
+    pop_size: usize,
+) -> Vec<u32> {
+    let shortest_distances = floyd_warshall::floyd_warshall(graph, |_edge| 1).unwrap();
+
+    // valves that can't be reached (at "i32::MAX" distance) are of no use
+    let relevant_nodes: Vec<_> = graph
+        .node_indices()
+        .filter(|idx| graph[*idx].flowrate > 0)
+        .filter(|idx| shortest_distances[&(start_node, *idx)] < i32::MAX)
+        .collect();
+
+    // generate population
+    let pop = generate_population(pop_size, relevant_nodes);
+
+    // evaluate population
+    pop.iter()
+        .map(|member| {
+            evaluate_member(
+                start_node,
+                member.to_owned(),
+                graph.clone(),
+                &shortest_distances,
+                minutes,
+            )
+        })
+        .collect()
+}
+
+/// Linear ranking selection: the members ordered by released pressure, best first,
+/// each with its fitness, which only depends on the rank.
+pub fn rank_population(pressures: &[u32]) -> Vec<(usize, f64)> {
+    let pop_size = pressures.len() as f64;
+    let mut idcs_sort = (0..pressures.len()).collect::<Vec<_>>();
+    idcs_sort.sort_by_key(|&i| pressures[i]);
+    idcs_sort.reverse();
+
+    idcs_sort
+        .into_iter()
+        .enumerate()
+        .map(|(rank, i)| {
+            let fitness = 2.0 / pop_size * (1.0 - (rank as f64 - 1.0) / (pop_size - 1.0));
+            (i, fitness)
+        })
+        .collect()
+}
+
+/// Random orders in which to visit the valves that have a flow rate.
+pub fn generate_population(pop_size: usize, relevant_nodes: Vec<NodeIndex>) -> Vec<Vec<NodeIndex>> {
+    let pop: Vec<Vec<NodeIndex>> = (0..pop_size)
+        .map(|_| {
+            let mut member = relevant_nodes.clone();
+            member.shuffle(&mut thread_rng());
+            member
+        })
+        .collect();
+    pop
+}
+
+/// Pressure released when walking the valves in the order given by a population member,
+/// opening each of them with a probability that grows with its flow rate. The walk ends
+/// when time is up.
+pub fn evaluate_member(
+    start_node: NodeIndex,
+    mut pop_member: Vec<NodeIndex>,
+    mut graph: ValveGraph,
+    shortest_distances: &HashMap<(NodeIndex, NodeIndex), i32>,
+    minutes: u32,
+) -> u32 {
+    pop_member.insert(0, start_node);
+    let mut time = 0;
+    let mut released_pressure = 0;
+    let mut flow_rates: Vec<u32> = Vec::new();
+    let mut rng = rand::thread_rng();
+    for reset_idx in graph.node_indices() {
+        graph[reset_idx].valve_open = false;
+    }
+
+    for i in 0..(pop_member.len() - 1) {
+        let start_idx = pop_member[i];
+        let target_idx = pop_member[i + 1];
+
+        for _t in 0..shortest_distances[&(start_idx, target_idx)] {
+            if time == minutes {
+                break;
+            }
+            time += 1;
+            let rel_p_permin: u32 = flow_rates.clone().iter().sum();
+            released_pressure += rel_p_permin;
+        }
+        if time == minutes {
+            break;
+        }
+
+        // open valve, or not
+        if !(graph[target_idx].valve_open || graph[target_idx].flowrate == 0) {
+            let max_flowrate = get_max_flowrate(&graph);
+
+            let open_threshold = 1.0 - (graph[target_idx].flowrate as f64) / (max_flowrate as f64);
+
+            let open_rng: f64 = rng.gen();
+            if open_rng > open_threshold {
+                time += 1;
+                // Update released pressure
+                let rel_p_permin: u32 = flow_rates.clone().iter().sum();
+                released_pressure += rel_p_permin;
+                flow_rates.push(graph[target_idx].flowrate);
+
+                graph[target_idx].valve_open = true;
+            }
+        }
+    }
+
+    let rel_p_permin: u32 = flow_rates.clone().iter().sum();
+    while time < minutes {
+        released_pressure += rel_p_permin;
+        time += 1;
+    }
+
+    released_pressure
+}
+
+/// Tunnels to valves that aren't described are errors pointing at their name.
+#[test]
+fn tunnel_to_unknown_valve() {
+    let lines = vec![
+        "Valve AA has flow rate=0; tunnels lead to valves BB, ZZ",
+        "Valve BB has flow rate=13; tunnel leads to valve AA",
+    ];
+    let err = parse_input(lines).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (1, 54, "ZZ"));
+}
+
+/// Valves that can't be reached are left out, and time is up after `minutes` even if
+/// the walk isn't over.
+#[test]
+fn unreachable_valves_and_time_limit() {
+    let input = "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                 Valve BB has flow rate=10; tunnel leads to valve AA\n\
+                 Valve CC has flow rate=20; tunnel leads to valve DD\n\
+                 Valve DD has flow rate=0; tunnel leads to valve CC\n";
+    let (graph, node_index_map) = Day16::parse(input).unwrap();
+    let start = node_index_map["AA"];
+    // BB is open after 2 minutes, then releases 10 per minute; it's opened with a
+    // chance of one half, as it has half the flow rate of CC
+    let pressures = evaluate_population(&graph, start, 5, 50);
+    assert!(pressures.iter().all(|&pressure| pressure <= 30));
+    assert!(pressures.contains(&30));
+    assert_eq!(evaluate_population(&graph, start, 1, 10), vec![0; 10]);
+}
+
+aoc_common::example_tests!(Day16);
@@ -0,0 +1,83 @@
+//! First attempt at part 1: Monte Carlo random walks through the tunnels. Way too slow to
+//! find the best way through the real input, but fine as an oracle for small inputs.
+use std::collections::HashMap;
+
+use anyhow::Result;
+use petgraph::graph::NodeIndex;
+use rand::Rng;
+
+use crate::{get_max_flowrate, idx_by_name, ValveGraph};
+
+/// Random walk starting at `start_valve`, opening valves on the way with a probability
+/// that grows with their flow rate. Returns the released pressure along with the path.
+/// In case I refactor this into struct/impl I can untangle this very procedural piece of code :-)
+pub fn valvewalk_mc(
+    mut graph: ValveGraph,
+    node_index_map: &HashMap<String, NodeIndex>,
+    start_valve: &str,
+    minutes: u32,
+) -> Result<(u32, Vec<String>)> {
+    // reset variables
+    let mut released_pressure = 0;
+    let mut path: Vec<String> = Vec::new();
+    let mut current_node = start_valve.to_owned();
+    let mut flow_rates: Vec<u32> = Vec::new();
+    let mut time = 0;
+    let mut rng = rand::thread_rng();
+    for reset_idx in graph.node_indices() {
+        graph[reset_idx].valve_open = false;
+    }
+
+    loop {
+        let idx = idx_by_name(node_index_map, current_node.as_str())?;
+        path.push(current_node.to_owned());
+
+        // open valve, or not
+        if !(graph[idx].valve_open || graph[idx].flowrate == 0) {
+            let max_flowrate = get_max_flowrate(&graph);
+
+            let open_threshold = 1.0 - (graph[idx].flowrate as f64) / (max_flowrate as f64);
+            // let open_threshold = 0.5;
+            let open_rng: f64 = rng.gen();
+            if open_rng > open_threshold {
+                graph[idx].valve_open = true;
+
+                flow_rates.push(graph[idx].flowrate);
+                path.push(String::from("Valve opened."));
+                // Update released pressure
+                for rate in &flow_rates {
+                    released_pressure += *rate;
+                }
+                time += 1;
+            }
+        }
+
+        if time + 1 >= minutes {
+            break;
+        }
+
+        // pick tunnel and move to next valve, or not
+        let conn_string = graph[idx].connections.clone();
+        let conns = conn_string
+            .split(", ")
+            .map(|e| e.to_owned())
+            .collect::<Vec<_>>();
+        let path_rng: f32 = rng.gen();
+        let path_choices = conns.len() - 1;
+        let path_coin = (path_rng * (path_choices as f32)).round() as usize;
+
+        current_node = conns[path_coin].clone();
+        time += 1;
+
+        // Update released pressure
+        for rate in &flow_rates {
+            released_pressure += *rate;
+        }
+
+        if time + 1 >= minutes {
+            break;
+        }
+    }
+
+    Ok((released_pressure, path))
+}
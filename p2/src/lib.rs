@@ -0,0 +1,342 @@
+//! Advent of Code day 2
+//! <https://adventofcode.com/2022/day/2>
+#![warn(missing_docs)]
+
+use std::io::BufRead;
+
+use anyhow::Result;
+use aoc_common::combinators::{
+    map, one_of, parse_line, parse_lines, separated_pair, tag, PResult, Span,
+};
+use aoc_common::{
+    Answer, GenerateInput, InputLine, InputRng, LineReader, NoParams, ParseError, Solution,
+};
+use rand::Rng;
+
+/// Day 2: Rock Paper Scissors.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Params = NoParams;
+    type Input<'a> = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Vec<Round>> {
+        Ok(parse_rounds(input.lines())?)
+    }
+
+    fn part1(rounds: &Vec<Round>, _params: &NoParams) -> Result<Answer> {
+        Ok(total_score(rounds, Interpretation::Shape).into())
+    }
+
+    fn part2(rounds: &Vec<Round>, _params: &NoParams) -> Result<Answer> {
+        Ok(total_score(rounds, Interpretation::Outcome).into())
+    }
+}
+
+/// `size` is the number of rounds in the strategy guide.
+impl GenerateInput for Day02 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        (0..size)
+            .map(|_| {
+                let opponent = rng.gen_range('A'..='C');
+                let response = rng.gen_range('X'..='Z');
+                format!("{opponent} {response}\n")
+            })
+            .collect()
+    }
+}
+
+/// A shape to play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// "A", or "X" in part 1.
+    Rock,
+    /// "B", or "Y" in part 1.
+    Paper,
+    /// "C", or "Z" in part 1.
+    Scissors,
+}
+
+impl Shape {
+    /// All shapes.
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// The shape this one beats. This is the only rule of the game, everything else
+    /// follows from it.
+    pub fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape beating this one.
+    pub fn beaten_by(self) -> Shape {
+        Shape::ALL
+            .into_iter()
+            .find(|shape| shape.beats() == self)
+            .expect("every shape is beaten by another")
+    }
+
+    /// The shape to play against this one for the given outcome.
+    pub fn response_for(self, outcome: Outcome) -> Shape {
+        match outcome {
+            Outcome::Loss => self.beats(),
+            Outcome::Draw => self,
+            Outcome::Win => self.beaten_by(),
+        }
+    }
+
+    /// Score for playing the shape, whatever the outcome.
+    pub fn score(self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+}
+
+/// The outcome of a round, for us.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// "X" in part 2.
+    Loss,
+    /// "Y" in part 2.
+    Draw,
+    /// "Z" in part 2.
+    Win,
+}
+
+impl Outcome {
+    /// The outcome of playing `me` against `opponent`.
+    pub fn of(me: Shape, opponent: Shape) -> Self {
+        if me.beats() == opponent {
+            Outcome::Win
+        } else if opponent.beats() == me {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Score for the outcome, whatever the shape played.
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// The second column of the strategy guide, whose meaning depends on the
+/// [`Interpretation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    /// "X"
+    X,
+    /// "Y"
+    Y,
+    /// "Z"
+    Z,
+}
+
+impl Column {
+    /// The column as a shape, see [`Interpretation::Shape`].
+    pub fn shape(self) -> Shape {
+        match self {
+            Column::X => Shape::Rock,
+            Column::Y => Shape::Paper,
+            Column::Z => Shape::Scissors,
+        }
+    }
+
+    /// The column as an outcome, see [`Interpretation::Outcome`].
+    pub fn outcome(self) -> Outcome {
+        match self {
+            Column::X => Outcome::Loss,
+            Column::Y => Outcome::Draw,
+            Column::Z => Outcome::Win,
+        }
+    }
+}
+
+/// How to read the second column of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    /// The shape to play, as we assumed in part 1.
+    Shape,
+    /// The outcome to aim for, as the elf explained in part 2.
+    Outcome,
+}
+
+impl Interpretation {
+    /// The shape we play in the round.
+    pub fn response(self, round: Round) -> Shape {
+        match self {
+            Interpretation::Shape => round.column.shape(),
+            Interpretation::Outcome => round.opponent.response_for(round.column.outcome()),
+        }
+    }
+}
+
+/// A line of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    /// The shape the opponent plays.
+    pub opponent: Shape,
+    /// What we should do.
+    pub column: Column,
+}
+
+impl Round {
+    /// Our score for the round: that of the shape we play plus that of the outcome.
+    pub fn score(self, interpretation: Interpretation) -> u32 {
+        let me = interpretation.response(self);
+        me.score() + Outcome::of(me, self.opponent).score()
+    }
+}
+
+/// Parse the strategy guide, a round per line.
+pub fn parse_rounds<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<Round>, ParseError> {
+    parse_lines(lines, round)
+}
+
+/// Parse a single line of the strategy guide, like "A Y": the opponent's shape and the
+/// second column separated by a space.
+pub fn parse_round(line: InputLine<'_>) -> Result<Round, ParseError> {
+    parse_line(line, round)
+}
+
+fn round(input: Span<'_>) -> PResult<'_, Round> {
+    let opponent = map(
+        one_of(&["A", "B", "C"], "\"A\", \"B\" or \"C\""),
+        |shape| match shape {
+            "A" => Shape::Rock,
+            "B" => Shape::Paper,
+            _ => Shape::Scissors,
+        },
+    );
+    let column = map(
+        one_of(&["X", "Y", "Z"], "\"X\", \"Y\" or \"Z\""),
+        |column| match column {
+            "X" => Column::X,
+            "Y" => Column::Y,
+            _ => Column::Z,
+        },
+    );
+    map(
+        separated_pair(opponent, tag(" "), column),
+        |(opponent, column)| Round { opponent, column },
+    )(input)
+}
+
+/// Total scores of both interpretations of the strategy guide, reading it lazily, one
+/// round at a time.
+pub fn total_scores<R: BufRead>(mut lines: LineReader<R>) -> Result<[u32; 2]> {
+    let mut total_scores = [0; 2];
+    while let Some(line) = lines.next_line()? {
+        let round = parse_round(line)?;
+        total_scores[0] += round.score(Interpretation::Shape);
+        total_scores[1] += round.score(Interpretation::Outcome);
+    }
+    Ok(total_scores)
+}
+
+/// Sum up the scores of all rounds, using one of the two interpretations of the
+/// strategy guide.
+pub fn total_score(rounds: &[Round], interpretation: Interpretation) -> u32 {
+    rounds.iter().map(|round| round.score(interpretation)).sum()
+}
+
+/// Score of a round given as in the strategy guide, e.g. "A Y".
+#[cfg(test)]
+fn score(round: &str, interpretation: Interpretation) -> u32 {
+    parse_round(InputLine::new(1, round))
+        .unwrap()
+        .score(interpretation)
+}
+
+/// Make sure matches involving paper and rock produce the correct score (part 1).
+#[test]
+fn test_paper_rock_part1() {
+    assert_eq!(8, score("A Y", Interpretation::Shape));
+    assert_eq!(1, score("B X", Interpretation::Shape));
+}
+
+/// Make sure matches involving rock and scissors produce the correct score (part 1).
+#[test]
+fn test_rock_scissors_part1() {
+    assert_eq!(3, score("A Z", Interpretation::Shape));
+    assert_eq!(7, score("C X", Interpretation::Shape));
+}
+
+/// Make sure matches involving scissors and paper produce the correct score (part 1).
+#[test]
+fn test_scissors_paper_part1() {
+    assert_eq!(9, score("B Z", Interpretation::Shape));
+    assert_eq!(2, score("C Y", Interpretation::Shape));
+}
+
+/// Make sure draw matches produce the correct score (part 1).
+#[test]
+fn test_draw_part1() {
+    assert_eq!(4, score("A X", Interpretation::Shape));
+    assert_eq!(5, score("B Y", Interpretation::Shape));
+    assert_eq!(6, score("C Z", Interpretation::Shape));
+}
+
+/// The scores derived from the rules agree with the score table of part 2.
+#[test]
+fn test_all_rounds_part2() {
+    let table = [
+        ("A X", 3),
+        ("B X", 1),
+        ("C X", 2),
+        ("A Y", 4),
+        ("B Y", 5),
+        ("C Y", 6),
+        ("A Z", 8),
+        ("B Z", 9),
+        ("C Z", 7),
+    ];
+    for (round, expected) in table {
+        assert_eq!(score(round, Interpretation::Outcome), expected, "{round}");
+    }
+}
+
+/// Each shape beats exactly one other, and the responses reach the outcome aimed for.
+#[test]
+fn test_rules() {
+    for shape in Shape::ALL {
+        assert_ne!(shape.beats(), shape);
+        assert_eq!(shape.beaten_by().beats(), shape);
+        for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+            assert_eq!(Outcome::of(shape.response_for(outcome), shape), outcome);
+        }
+    }
+}
+
+/// Unknown shapes are errors pointing at them, rather than scoring 0.
+#[test]
+fn test_invalid_rounds() {
+    let err = parse_rounds(["A Y", "B W"]).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "W"));
+    let err = parse_round(InputLine::new(1, "AX")).unwrap_err();
+    assert_eq!((err.column, err.expected.as_str()), (2, "\" \""));
+    assert!(parse_round(InputLine::new(1, "A X ")).is_err());
+}
+
+/// Streaming the example gives the same scores as the puzzle description.
+#[test]
+fn test_total_scores_streamed() {
+    let lines = LineReader::new(include_str!("../input_test.txt").as_bytes());
+    assert_eq!(total_scores(lines).unwrap(), [15, 12]);
+}
+
+aoc_common::example_tests!(Day02);
@@ -0,0 +1,287 @@
+//! Advent of Code day 3
+//! <https://adventofcode.com/2022/day/3>
+#![warn(missing_docs)]
+
+use std::io::BufRead;
+
+use anyhow::{bail, Result};
+use aoc_common::combinators::{parse_line, repeat_to_end, satisfy};
+use aoc_common::{
+    Answer, GenerateInput, InputLine, InputRng, LineReader, NoParams, ParseError, Solution,
+};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Day 3: Rucksack Reorganization.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Params = NoParams;
+    type Input<'a> = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(parse_rucksacks(input.lines())?)
+    }
+
+    fn part1(rucksacks: &Vec<String>, _params: &NoParams) -> Result<Answer> {
+        Ok(misplaced_priority_sum(rucksacks)?.into())
+    }
+
+    fn part2(rucksacks: &Vec<String>, _params: &NoParams) -> Result<Answer> {
+        Ok(badge_priority_sum(rucksacks)?.into())
+    }
+}
+
+/// `size` is the number of rucksacks, rounded up to whole groups of three elves. Like in
+/// the puzzle input, exactly one item is in both compartments of a rucksack, and exactly
+/// one (the badge) in all rucksacks of a group.
+impl GenerateInput for Day03 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        let mut rucksacks = String::new();
+        for _group in 0..size.div_ceil(3).max(1) {
+            let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+            items.shuffle(rng);
+            let badge = items[0];
+            // every elf of the group picks from 17 items of their own, so only the badge is shared
+            for elf_items in items[1..].chunks_exact(17) {
+                let misplaced = elf_items[0];
+                let (left_items, right_items) = elf_items[1..].split_at(8);
+                let compartment_len = rng.gen_range(3..=16);
+                let mut left = vec![badge, misplaced];
+                left.extend((2..compartment_len).map(|_| left_items.choose(rng).unwrap()));
+                let mut right = vec![misplaced];
+                right.extend((1..compartment_len).map(|_| right_items.choose(rng).unwrap()));
+                left.shuffle(rng);
+                right.shuffle(rng);
+                rucksacks.extend(left.into_iter().chain(right));
+                rucksacks.push('\n');
+            }
+        }
+        rucksacks
+    }
+}
+
+/// Read the rucksacks, one per line. Items are letters, and both compartments hold
+/// the same number of items, so "bisect_rucksack" needs an even number of them. The
+/// elves come in groups of three, so the number of rucksacks has to be a multiple of 3.
+pub fn parse_rucksacks<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<String>, ParseError> {
+    let rucksacks = InputLine::numbered(lines)
+        .map(|line| parse_rucksack(line).map(str::to_owned))
+        .collect::<Result<Vec<_>, _>>()?;
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(incomplete_group(rucksacks.len()));
+    }
+    Ok(rucksacks)
+}
+
+/// Check a single rucksack, see [`parse_rucksacks`].
+pub fn parse_rucksack(line: InputLine<'_>) -> Result<&str, ParseError> {
+    let items = parse_line(
+        line,
+        repeat_to_end(satisfy(|c| c.is_ascii_alphabetic(), "an item letter")),
+    )?;
+    if !items.len().is_multiple_of(2) {
+        return Err(line.error("an even number of items"));
+    }
+    Ok(line.text)
+}
+
+/// Both priority sums, of the misplaced items and of the badges, reading the rucksacks
+/// lazily. Only the rucksacks of the current group of three elves are kept around.
+pub fn priority_sums<R: BufRead>(mut lines: LineReader<R>) -> Result<[u32; 2]> {
+    let alphabet = get_alphabet();
+    let mut priority_sums = [0; 2];
+    // the buffers are reused from group to group
+    let mut group = vec![String::new(); 3];
+    let mut nr_in_group = 0;
+    let mut nr_rucksacks = 0;
+    while let Some(line) = lines.next_line()? {
+        nr_rucksacks = line.nr;
+        let rucksack = parse_rucksack(line)?;
+        let misplaced = find_misplaced_item(rucksack)?;
+        priority_sums[0] += get_priority_score(&misplaced.to_string(), &alphabet)?;
+
+        group[nr_in_group].clear();
+        group[nr_in_group].push_str(rucksack);
+        nr_in_group += 1;
+        if nr_in_group == group.len() {
+            let badge = find_badge(&group)?;
+            priority_sums[1] += get_priority_score(&badge.to_string(), &alphabet)?;
+            nr_in_group = 0;
+        }
+    }
+    if nr_in_group != 0 {
+        return Err(incomplete_group(nr_rucksacks).into());
+    }
+    Ok(priority_sums)
+}
+
+/// Error for input ending in the middle of a group of three elves, after `nr_rucksacks`.
+fn incomplete_group(nr_rucksacks: usize) -> ParseError {
+    let nr_missing = 3 - nr_rucksacks % 3;
+    InputLine::new(nr_rucksacks + 1, "").error(format!(
+        "{nr_missing} more rucksack(s) to complete the last group of three elves"
+    ))
+}
+
+/// First, the misplaced items: sum of the priorities of items present in both compartments.
+pub fn misplaced_priority_sum(rucksacks: &Vec<String>) -> Result<u32> {
+    let misplaced_items = find_misplaced_items(rucksacks)?;
+    let alphabet = get_alphabet();
+    get_priority_sum(misplaced_items, &alphabet)
+}
+
+/// Now the badges: sum of the priorities of the items common to each group of three elves.
+pub fn badge_priority_sum(rucksacks: &[String]) -> Result<u32> {
+    let badges = find_badges(rucksacks)?;
+    let alphabet = get_alphabet();
+    let mut badge_priorities = Vec::new();
+    for item in badges {
+        badge_priorities.push(get_priority_score(&item, &alphabet)?);
+    }
+    Ok(badge_priorities.iter().sum())
+}
+
+/// get our alphabet in an order where the index of a char corresponds
+/// to its priority as described here: <https://adventofcode.com/2022/day/3>
+fn get_alphabet() -> String {
+    String::from_utf8((b'a'..=b'z').chain(b'A'..=b'Z').collect()).unwrap()
+}
+
+/// Split rucksack into two equally sized compartments. We can
+/// rely on the fact that this works since all rucksacks contain
+/// an even amount of items.
+fn bisect_rucksack(rucksack: &str) -> Result<(&str, &str)> {
+    Ok(rucksack.split_at(rucksack.len() / 2))
+}
+
+/// Find any "items" present in both rucksack compartments.
+fn find_misplaced_items(rucksacks: &Vec<String>) -> Result<Vec<String>> {
+    let mut misplaced_items: Vec<String> = Vec::new();
+    for rucksack in rucksacks {
+        misplaced_items.push(find_misplaced_item(rucksack)?.to_string());
+    }
+    Ok(misplaced_items)
+}
+
+/// Find the item present in both compartments of a rucksack.
+fn find_misplaced_item(rucksack: &str) -> Result<char> {
+    let (comp1, comp2) = bisect_rucksack(rucksack)?;
+    match comp1.chars().find(|c| comp2.contains(*c)) {
+        Some(item) => Ok(item),
+        None => bail!("No item is in both compartments of rucksack {rucksack:?}."),
+    }
+}
+
+/// Find "badges", i.e. common items within groups of three consecutive
+/// elf backpacks.
+fn find_badges(rucksacks: &[String]) -> Result<Vec<String>> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(incomplete_group(rucksacks.len()).into());
+    }
+    let mut badges: Vec<String> = Vec::new();
+    for group in rucksacks.chunks_exact(3) {
+        badges.push(find_badge(group)?.to_string());
+    }
+    Ok(badges)
+}
+
+/// Find the badge of a group of three elves, the item all of their backpacks contain.
+fn find_badge(group: &[String]) -> Result<char> {
+    let [first, second, third] = group else {
+        bail!("A group has {} elves instead of three.", group.len());
+    };
+    match first
+        .chars()
+        .find(|c| second.contains(*c) && third.contains(*c))
+    {
+        Some(badge) => Ok(badge),
+        None => bail!("The rucksacks of a group have no item in common: {group:?}."),
+    }
+}
+
+/// Calculate sum of item priorites, making use of "get_priority_score".
+fn get_priority_sum(misplaced_items: Vec<String>, alphabet: &str) -> Result<u32, anyhow::Error> {
+    let mut priorities = Vec::new();
+    for item in misplaced_items {
+        priorities.push(get_priority_score(&item, alphabet)?);
+    }
+    let priority_sum: u32 = priorities.iter().sum();
+    Ok(priority_sum)
+}
+
+/// Use index of char representing an item in the constructed "alphabet"
+/// as priority score.
+fn get_priority_score(item: &str, alphabet: &str) -> Result<u32> {
+    let item_idx = alphabet
+        .chars()
+        .position(|e| e == item.chars().next().expect("Item is not a valid &str?"))
+        .expect("Item has no representation in alphabet?");
+    let priority_score = item_idx as u32 + 1;
+    Ok(priority_score)
+}
+
+/// "bisect_rucksack" is not validated to work for odd numbers so let's see
+/// whether we can safely assume an even number of items per backpack, as understood
+/// from the puzzle description.
+#[test]
+fn test_even_number_of_items_in_rucksacks() {
+    let rucksacks = include_str!("../input_test.txt").lines();
+    for rucksack in rucksacks {
+        assert_eq!(rucksack.len(), 2 * rucksack.len() / 2); // this should just panic for odd rucksack lengthts
+    }
+}
+
+/// Check if we can split a string, and recover the original
+/// by putting it back together again.
+#[test]
+fn test_bisect_rucksack() {
+    let test_rucksack = "owWilPIkdloPowkeIDKk";
+    let (comp1, comp2) = bisect_rucksack(test_rucksack).unwrap();
+    let rebuilt_rucksack: String = String::from(comp1) + comp2;
+    assert_eq!(test_rucksack, &rebuilt_rucksack);
+}
+
+/// Streaming the example gives the same sums as the puzzle description.
+#[test]
+fn test_priority_sums_streamed() {
+    let lines = LineReader::new(include_str!("../input_test.txt").as_bytes());
+    assert_eq!(priority_sums(lines).unwrap(), [157, 70]);
+}
+
+/// A number of rucksacks that isn't a multiple of 3 is an error, in both ways of
+/// reading the input.
+#[test]
+fn test_incomplete_group() {
+    let err = Day03::parse("abab").unwrap_err();
+    let streamed = priority_sums(LineReader::new("abab".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), streamed.to_string());
+    let err = err.downcast_ref::<ParseError>().unwrap();
+    assert_eq!(
+        (err.line, err.expected.as_str()),
+        (
+            2,
+            "2 more rucksack(s) to complete the last group of three elves"
+        )
+    );
+}
+
+/// Rucksacks without a misplaced item, or groups without a badge, are errors in both
+/// ways of reading the input.
+#[test]
+fn test_missing_misplaced_item_and_badge() {
+    for (input, solve) in [
+        ("abcd\nabab\nabab", Day03::part1 as fn(&_, &_) -> _),
+        ("abab\ncdcd\nefef", Day03::part2),
+    ] {
+        let rucksacks = Day03::parse(input).unwrap();
+        let err = solve(&rucksacks, &NoParams::default()).unwrap_err();
+        let streamed = priority_sums(LineReader::new(input.as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), streamed.to_string());
+    }
+}
+
+aoc_common::example_tests!(Day03);
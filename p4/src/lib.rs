@@ -0,0 +1,142 @@
+//! Advent of Code day 4
+//! <https://adventofcode.com/2022/day/4>
+#![warn(missing_docs)]
+
+use std::io::BufRead;
+
+use anyhow::Result;
+use aoc_common::combinators::{parse_line, separated_pair, tag, uint};
+use aoc_common::{
+    Answer, GenerateInput, InputLine, InputRng, LineReader, NoParams, ParseError, Solution,
+};
+use rand::Rng;
+
+/// Day 4: Camp Cleanup.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Params = NoParams;
+    type Input<'a> = Vec<Assignment>;
+
+    fn parse(input: &str) -> Result<Vec<Assignment>> {
+        Ok(parse_assignments(input.lines().collect())?)
+    }
+
+    fn part1(assignments: &Vec<Assignment>, _params: &NoParams) -> Result<Answer> {
+        Ok(find_full_double_assignments(assignments)?.into())
+    }
+
+    fn part2(assignments: &Vec<Assignment>, _params: &NoParams) -> Result<Answer> {
+        Ok(find_partial_double_assignments(assignments)?.into())
+    }
+}
+
+/// `size` is the number of elf teams.
+impl GenerateInput for Day04 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        let sections = |rng: &mut InputRng| {
+            let (start, end) = (rng.gen_range(1..=99), rng.gen_range(1..=99));
+            (start.min(end), start.max(end))
+        };
+        (0..size)
+            .map(|_| {
+                let (first, second) = (sections(rng), sections(rng));
+                format!("{}-{},{}-{}\n", first.0, first.1, second.0, second.1)
+            })
+            .collect()
+    }
+}
+
+/// Section ranges (start and end, both inclusive) of the two elves of a team.
+pub type Assignment = ((usize, usize), (usize, usize));
+
+/// Parse lines like "2-4,6-8" into the section ranges of both elves.
+pub fn parse_assignments(lines: Vec<&str>) -> Result<Vec<Assignment>, ParseError> {
+    InputLine::numbered(lines).map(parse_assignment).collect()
+}
+
+/// Parse a single line, see [`parse_assignments`].
+pub fn parse_assignment(line: InputLine) -> Result<Assignment, ParseError> {
+    let range = || {
+        let section = || uint("a section number");
+        separated_pair(section(), tag("-"), section())
+    };
+    parse_line(line, separated_pair(range(), tag(","), range()))
+}
+
+/// Number of teams where one elf's sections cover all of the other's (part 1).
+pub fn find_full_double_assignments(assignments: &[Assignment]) -> Result<usize> {
+    Ok(assignments.iter().filter(|&&a| is_full_overlap(a)).count())
+}
+
+/// Number of teams whose sections overlap at all (part 2).
+pub fn find_partial_double_assignments(assignments: &[Assignment]) -> Result<usize> {
+    Ok(assignments
+        .iter()
+        .filter(|&&a| is_partial_overlap(a))
+        .count())
+}
+
+/// Does one elf of the team have to clean all sections of the other one?
+pub fn is_full_overlap(((start_1, end_1), (start_2, end_2)): Assignment) -> bool {
+    ((start_1 <= start_2) && (end_1 >= end_2)) | ((start_2 <= start_1) && (end_2 >= end_1))
+}
+
+/// Do the elves of the team have to clean any section both?
+pub fn is_partial_overlap(((start_1, end_1), (start_2, end_2)): Assignment) -> bool {
+    (start_1 <= end_2) && (end_1 >= start_2)
+}
+
+/// Count the teams with full and with partial overlaps, reading the assignments lazily,
+/// one team at a time.
+pub fn count_overlaps<R: BufRead>(mut lines: LineReader<R>) -> Result<[usize; 2]> {
+    let mut overlaps = [0; 2];
+    while let Some(line) = lines.next_line()? {
+        let assignment = parse_assignment(line)?;
+        overlaps[0] += usize::from(is_full_overlap(assignment));
+        overlaps[1] += usize::from(is_partial_overlap(assignment));
+    }
+    Ok(overlaps)
+}
+
+/// Uses the example from the puzzle description to check if "find_full_double_assignment" works.
+#[test]
+fn test_find_full_double_assignment() {
+    let test_assignments = vec![
+        "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+    ];
+    let assignments = parse_assignments(test_assignments).unwrap();
+    assert_eq!(find_full_double_assignments(&assignments).unwrap(), 2_usize)
+}
+
+/// Uses the example from the puzzle description to check if "find_partial_double_assignment" works.
+#[test]
+fn test_find_partial_double_assignment() {
+    let test_assignments = vec![
+        "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+    ];
+    let assignments = parse_assignments(test_assignments).unwrap();
+    assert_eq!(
+        find_partial_double_assignments(&assignments).unwrap(),
+        4_usize
+    )
+}
+
+/// Malformed lines are reported with their position.
+#[test]
+fn test_parse_assignments_reports_position() {
+    let err = parse_assignments(vec!["2-4,6-8", "2-3;4-5"]).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (2, 4, ";"));
+    let err = parse_assignments(vec!["2-4,6-x"]).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (1, 7, "x"));
+}
+
+/// Streaming the example counts the same overlaps.
+#[test]
+fn test_count_overlaps_streamed() {
+    let lines = LineReader::new(include_str!("../input_test.txt").as_bytes());
+    assert_eq!(count_overlaps(lines).unwrap(), [2, 4]);
+}
+
+aoc_common::example_tests!(Day04);
@@ -0,0 +1,324 @@
+//! Advent of Code day 5
+//! <https://adventofcode.com/2022/day/5>
+//!
+//! This was actually interesting, and getting the input into a usable form
+//! required a bit of playing around.
+//!
+//! I went with representing all the data in terms of vectors. Probably
+//! noobish, on the other hand, we have a lot of dynamic  shuffling of items
+//! between containers, so vectors are perhaps OK?
+//! We'll see after looking at other peoples' solutions...
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::combinators::{
+    alt, delimited, map, pair, parse_line, preceded, repeat_to_end, satisfy, separated_list0,
+    space0, spanned, tag, try_map, uint,
+};
+use aoc_common::{Answer, GenerateInput, InputLine, InputRng, NoParams, ParseError, Solution};
+use rand::Rng;
+
+/// Day 5: Supply Stacks.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Params = NoParams;
+    type Input<'a> = (Vec<Vec<String>>, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_crates_and_moves(input.lines().collect())?)
+    }
+
+    fn part1((crates, moves): &Self::Input<'_>, _params: &NoParams) -> Result<Answer> {
+        Ok(rearrange_crates(crates.clone(), moves, move_crates_by_instruction_part1)?.into())
+    }
+
+    fn part2((crates, moves): &Self::Input<'_>, _params: &NoParams) -> Result<Answer> {
+        Ok(rearrange_crates(crates.clone(), moves, move_crates_by_instruction_part2)?.into())
+    }
+}
+
+/// `size` is the number of instructions. There are up to nine stacks of up to eight
+/// crates, and the instructions never take more crates from a stack than it holds.
+impl GenerateInput for Day05 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        let nr_stacks = rng.gen_range(3..=9);
+        let stacks = (0..nr_stacks)
+            .map(|_| {
+                let height = rng.gen_range(1..=8);
+                (0..height).map(|_| rng.gen_range('A'..='Z')).collect()
+            })
+            .collect::<Vec<Vec<char>>>();
+
+        // drawing of the stacks, from the top down
+        let mut input = String::new();
+        let max_height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..max_height).rev() {
+            let row = stacks
+                .iter()
+                .map(|stack| {
+                    stack
+                        .get(level)
+                        .map_or("   ".to_owned(), |c| format!("[{c}]"))
+                })
+                .collect::<Vec<_>>();
+            input += &row.join(" ");
+            input.push('\n');
+        }
+        let labels = (1..=nr_stacks)
+            .map(|nr| format!(" {nr} "))
+            .collect::<Vec<_>>();
+        input += &labels.join(" ");
+        input += "\n\n";
+
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+        for _step in 0..size {
+            let source = loop {
+                let source = rng.gen_range(0..nr_stacks);
+                if heights[source] > 0 {
+                    break source;
+                }
+            };
+            let target = (source + rng.gen_range(1..nr_stacks)) % nr_stacks;
+            let quantity = rng.gen_range(1..=heights[source]);
+            heights[source] -= quantity;
+            heights[target] += quantity;
+            input += &format!("move {quantity} from {} to {}\n", source + 1, target + 1);
+        }
+        input
+    }
+}
+
+/// One instruction like "move 1 from 2 to 1", with the stack numbers already
+/// translated to indices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    /// Number of crates to move.
+    pub quantity: usize,
+    /// Index of the stack the crates are taken from.
+    pub source: usize,
+    /// Index of the stack the crates are put on.
+    pub target: usize,
+}
+
+/// Perform all algorithm steps using the given crane model, and get the crates
+/// on top of each stack afterwards.
+pub fn rearrange_crates(
+    mut crates: Vec<Vec<String>>,
+    moves: &[Move],
+    move_crates_by_instruction: fn(&Move, &mut [Vec<String>]) -> Result<()>,
+) -> Result<String> {
+    for instruction in moves {
+        move_crates_by_instruction(instruction, &mut crates)?;
+    }
+    get_crates_on_top(crates)
+}
+
+/// Parse the whole input into the crate stacks and the instructions.
+pub fn parse_crates_and_moves(
+    lines: Vec<&str>,
+) -> Result<(Vec<Vec<String>>, Vec<Move>), ParseError> {
+    // Split data into relevant parts
+    let (crate_stack_raw, instructions, baseline) = parse_and_segment_input(lines)?;
+
+    let crates = build_crate_vec(baseline, crate_stack_raw)?;
+    let moves = instructions
+        .into_iter()
+        .map(|instruction| parse_move(instruction, crates.len()))
+        .collect::<Result<_, _>>()?;
+    Ok((crates, moves))
+}
+
+/// Parse input data into:
+/// a) the representation of our crate stack as a vector of lines,
+/// b) the instruction list as a vector of lines,
+/// c) the "base line" enumerating the crate stacks in corresponding with the instruction list.
+pub fn parse_and_segment_input(
+    lines: Vec<&str>,
+) -> Result<(Vec<InputLine<'_>>, Vec<InputLine<'_>>, InputLine<'_>), ParseError> {
+    let lines = InputLine::numbered(lines).collect::<Vec<_>>();
+    let separator = lines
+        .iter()
+        .position(|line| line.text.is_empty())
+        .ok_or_else(|| {
+            let last_line = lines.last().copied().unwrap_or(InputLine::new(1, ""));
+            let end = &last_line.text[last_line.text.len()..];
+            last_line.error_at(end, "an empty line between the crates and the instructions")
+        })?;
+    let mut crate_stack = lines[..separator].to_owned();
+    let instructions = lines[separator + 1..].to_owned();
+    let baseline = crate_stack
+        .pop()
+        .ok_or_else(|| lines[separator].error("the crate stacks"))?;
+    Ok((crate_stack, instructions, baseline))
+}
+
+/// The input data has a cute vertical representation of the crate stacks. This function
+/// parses this representation into a vector of vectors of strings. Strings represent
+/// crates, inner vectors each single stacks, and the outer vector collects the stacks.
+pub fn build_crate_vec(
+    baseline: InputLine,
+    crate_stack: Vec<InputLine>,
+) -> Result<Vec<Vec<String>>, ParseError> {
+    // the base line numbers the stacks, padded with spaces: " 1   2   3 "
+    let stack_label = delimited(space0, spanned(uint::<usize>("a stack number")), space0);
+    let stack_labels = parse_line(baseline, repeat_to_end(stack_label))?;
+    for (stack_nr, &(label, nr)) in (1..).zip(&stack_labels) {
+        if nr != stack_nr {
+            return Err(baseline.error_at(label, format!("stack number {stack_nr}")));
+        }
+    }
+    let nr_stacks = stack_labels.len();
+    // each line above has a slot per stack, either a crate like "[Z]" or three spaces.
+    // Lines may end early where there are no crates.
+    let slot = alt((
+        map(
+            delimited(
+                tag("["),
+                satisfy(|ch| ch.is_ascii_uppercase(), "a crate letter"),
+                tag("]"),
+            ),
+            Some,
+        ),
+        map(tag("   "), |_| None),
+    ));
+    let mut crates = vec![Vec::new(); nr_stacks];
+    // In the original representation, the "upper" crates to be manupulated are the first
+    // ones. Here, we revert the order so we can rather use "pop" and "push" on the stacks.
+    for line in crate_stack.into_iter().rev() {
+        let slots = parse_line(line, separated_list0(tag(" "), &slot))?;
+        for (stack_vec, label) in crates.iter_mut().zip(slots) {
+            stack_vec.extend(label.map(String::from));
+        }
+    }
+    Ok(crates)
+}
+
+/// Parse an instruction like "move 1 from 2 to 1", checking that both stacks exist.
+pub fn parse_move(line: InputLine, nr_stacks: usize) -> Result<Move, ParseError> {
+    let stack_idx = || {
+        try_map(uint::<usize>("a stack number"), move |nr| match nr {
+            1.. if nr <= nr_stacks => Ok(nr - 1), // translate to indices
+            _ => Err(format!("a stack number from 1 to {nr_stacks}")),
+        })
+    };
+    let instruction = pair(
+        preceded(tag("move "), uint("a number of crates")),
+        pair(
+            preceded(tag(" from "), stack_idx()),
+            preceded(tag(" to "), stack_idx()),
+        ),
+    );
+    let (quantity, (source, target)) = parse_line(line, instruction)?;
+    Ok(Move {
+        quantity,
+        source,
+        target,
+    })
+}
+
+/// This function takes a mutable reference to "crates" and shuffles around the crates
+/// according to the puzzle instructions, part 1 ("CrateMover 9000" :-)).
+pub fn move_crates_by_instruction_part1(step1: &Move, crates: &mut [Vec<String>]) -> Result<()> {
+    let remaining_crates = remaining_crates(step1, crates)?;
+    // Shuffling has to be done sequentially in a loop as per instructions, otherwise the order
+    // of the boxes will be wrong.
+    let taken_crates = crates[step1.source].split_off(remaining_crates);
+    for moved_crate in taken_crates.into_iter().rev() {
+        crates[step1.target].push(moved_crate);
+    }
+    Ok(())
+}
+
+/// This function takes a mutable reference to "crates" and shuffles around the crates
+/// according to the puzzle instructions, part 2 ("CrateMover 9001" :-)). Actually
+/// this was my first solutions since I didn't read the instructions of part 1 properly...
+pub fn move_crates_by_instruction_part2(step1: &Move, crates: &mut [Vec<String>]) -> Result<()> {
+    let removed_crates = remaining_crates(step1, crates)?;
+    let mut stack_tail: Vec<String> = crates[step1.source].drain(removed_crates..).collect();
+    crates[step1.target].append(&mut stack_tail);
+    Ok(())
+}
+
+/// Number of crates left on the source stack after the move, the same check for both
+/// crane models: an instruction can't take more crates than the stack holds.
+fn remaining_crates(step: &Move, crates: &[Vec<String>]) -> Result<usize> {
+    let stack = &crates[step.source];
+    stack.len().checked_sub(step.quantity).ok_or_else(|| {
+        anyhow!(
+            "Can't move {} crates from stack {}, it only holds {}.",
+            step.quantity,
+            step.source + 1,
+            stack.len()
+        )
+    })
+}
+
+/// Collect the crates "on top" (last elements of each stack vector) into a string to be
+/// typed into the puzzle solution form.
+pub fn get_crates_on_top(crates: Vec<Vec<String>>) -> Result<String> {
+    let mut result_str = String::new();
+    for cr in crates {
+        result_str.push_str(cr.last().map_or(" ", String::as_str));
+    }
+    Ok(result_str)
+}
+
+/// Apply our algorithm to test data from the puzzle description, part 1
+#[test]
+fn test_algo_part1_on_test_input() {
+    // input data from example
+    let lines = include_str!("../input_test.txt") // Just learned that we can just inline the puzzle input, so why not?
+        .lines()
+        .collect::<Vec<_>>();
+    // apply algorithm steps
+    let (mut crates, moves) = parse_crates_and_moves(lines).unwrap();
+    for instruction in &moves {
+        move_crates_by_instruction_part1(instruction, &mut crates).unwrap();
+    }
+    let result = get_crates_on_top(crates).unwrap();
+
+    assert_eq!(result, "CMZ");
+}
+
+/// Apply our algorithm to test data from the puzzle description, part 2
+#[test]
+fn test_algo_part2_on_test_input() {
+    // input data from example
+    let lines = include_str!("../input_test.txt") // Just learned that we can just inline the puzzle input, so why not?
+        .lines()
+        .collect::<Vec<_>>();
+    // apply algorithm steps
+    let (mut crates, moves) = parse_crates_and_moves(lines).unwrap();
+    for instruction in &moves {
+        move_crates_by_instruction_part2(instruction, &mut crates).unwrap();
+    }
+    let result = get_crates_on_top(crates).unwrap();
+
+    assert_eq!(result, "MCD");
+}
+
+/// Instructions referring to stacks that don't exist are reported with their position.
+#[test]
+fn test_parse_move_checks_stacks() {
+    let line = InputLine::new(7, "move 1 from 4 to 1");
+    let err = parse_move(line, 3).unwrap_err();
+    assert_eq!((err.line, err.column, err.found.as_str()), (7, 13, "4"));
+}
+
+/// Both crane models refuse to take more crates from a stack than it holds.
+#[test]
+fn test_move_too_many_crates() {
+    let input = "[A]    \n[B] [C]\n 1   2 \n\nmove 3 from 1 to 2\n";
+    let crates_and_moves = Day05::parse(input).unwrap();
+    let err1 = Day05::part1(&crates_and_moves, &NoParams::default()).unwrap_err();
+    let err2 = Day05::part2(&crates_and_moves, &NoParams::default()).unwrap_err();
+    assert_eq!(
+        err1.to_string(),
+        "Can't move 3 crates from stack 1, it only holds 2."
+    );
+    assert_eq!(err2.to_string(), err1.to_string());
+}
+
+aoc_common::example_tests!(Day05);
@@ -1,13 +1,13 @@
-/// Arena tree node representing a directory. Inspiration:
-/// https://dev.to/deciduously/no-more-tears-no-more-knots-arena-allocated-trees-in-rust-44k6
-///
-/// Beware: The original tree from the article had a "node" function used to
-/// create a new node or return its index depending on whether a node with the
-/// provided name is already present in the tree.
-/// This was not compatible with the example, however, since the names of the directories
-/// (=nodes) are not unique! This caused wrong (even circular) parent-child relationships,
-/// and an infinite recursion in the "add_subdir_sizes" function from the main file,
-/// causing a stack overflow :-) The adapted version below works.
+//! Arena tree node representing a directory. Inspiration:
+//! <https://dev.to/deciduously/no-more-tears-no-more-knots-arena-allocated-trees-in-rust-44k6>
+//!
+//! Beware: The original tree from the article had a "node" function used to
+//! create a new node or return its index depending on whether a node with the
+//! provided name is already present in the tree.
+//! This was not compatible with the example, however, since the names of the directories
+//! (=nodes) are not unique! This caused wrong (even circular) parent-child relationships,
+//! and an infinite recursion in the "add_subdir_sizes" function from the main file,
+//! causing a stack overflow :-) The adapted version below works.
 
 /// Tree node. Each node has an index which is used to identify it. Consequently, also parent
 /// and child nodes are represented by their indices, stored in the "parent" Option or
@@ -16,10 +16,15 @@
 /// invoking the wrath of the borrow checker.
 #[derive(Debug)]
 pub struct Node {
+    /// Index of the node in the arena.
     pub idx: usize,
+    /// Directory name, which is not unique within the tree.
     pub name: String,
+    /// Total size of the files directly in this directory, without subdirectories.
     pub size: u32,
+    /// Index of the parent directory, `None` for the root.
     pub parent: Option<usize>,
+    /// Indices of the subdirectories.
     pub children: Vec<usize>,
 }
 impl Node {
@@ -44,6 +49,7 @@ impl Node {
 /// of them identified by its index.
 #[derive(Debug, Default)]
 pub struct DirTree {
+    /// All nodes, the root comes first.
     pub arena: Vec<Node>,
 }
 impl DirTree {
@@ -0,0 +1,251 @@
+//! Advent of Code day 7
+//! <https://adventofcode.com/2022/day/7>
+//!
+//! Oh, we could resolve this using a tree. Now I know trees in Rust can be painful.
+//! Digging around, it seems that "Arena-allocated Trees" are the way to go.
+//! There are a bunch of crates, but to me it would seem reasonable to just use
+//! "petgraph" (supporting all kinds of graphs, API looks nice)
+//!
+//! Since we are here to learn, we will try to roll our own (see module "dirtree")!
+//! Taking this article as inspiration:
+//! <https://dev.to/deciduously/no-more-tears-no-more-knots-arena-allocated-trees-in-rust-44k6>
+#![warn(missing_docs)]
+
+pub mod dirtree;
+use anyhow::{anyhow, Result};
+use aoc_common::combinators::{alt, map, parse_line, preceded, tag, take_while1, terminated, uint};
+use aoc_common::{Answer, GenerateInput, InputLine, InputRng, ParseError, Solution};
+use dirtree::DirTree;
+use rand::Rng;
+use serde::Deserialize;
+
+/// Day 7: No Space Left On Device.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Params = Params;
+    type Input<'a> = DirTree;
+
+    fn parse(input: &str) -> Result<DirTree> {
+        Ok(build_dirtree(input.lines().collect())?)
+    }
+
+    fn part1(dir_tree: &DirTree, _params: &Params) -> Result<Answer> {
+        Ok(get_sum_lt100k(dir_tree)?.into())
+    }
+
+    fn part2(dir_tree: &DirTree, params: &Params) -> Result<Answer> {
+        Ok(find_dir_to_del(dir_tree, params.space_total, params.space_update)?.into())
+    }
+}
+
+/// `size` is the number of files, spread over a fifth as many directories. Together they
+/// take up more space than is left for the update, but still fit on the disk.
+impl GenerateInput for Day07 {
+    fn generate_input(rng: &mut InputRng, size: usize, params: &Params) -> String {
+        // random tree: every directory but the root gets a parent created before it
+        let nr_dirs = size / 5 + 1;
+        let mut subdirs = vec![Vec::new(); nr_dirs];
+        for dir in 1..nr_dirs {
+            subdirs[rng.gen_range(0..dir)].push(dir);
+        }
+
+        // split the used space into the file sizes
+        let used_min = params.space_total.saturating_sub(params.space_update) + 1;
+        let used = rng.gen_range(used_min.min(params.space_total)..=params.space_total);
+        let mut cuts = (1..size.max(1))
+            .map(|_| rng.gen_range(0..=used))
+            .collect::<Vec<_>>();
+        cuts.extend([0, used]);
+        cuts.sort();
+        let mut files = vec![Vec::new(); nr_dirs];
+        for file_size in cuts.windows(2).map(|cut| cut[1] - cut[0]) {
+            let file_name = format!("{}.{}", random_name(rng, 8), random_name(rng, 3));
+            files[rng.gen_range(0..nr_dirs)].push(format!("{file_size} {file_name}"));
+        }
+
+        let dir_names = (0..nr_dirs)
+            .map(|_| random_name(rng, 8))
+            .collect::<Vec<_>>();
+        let mut transcript = "$ cd /\n".to_owned();
+        write_listing(0, &subdirs, &files, &dir_names, &mut transcript);
+        transcript
+    }
+}
+
+/// Puzzle parameters, see `[day7]` in aoc.toml.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Size of the whole disk.
+    pub space_total: u32,
+    /// Free space needed by the update.
+    pub space_update: u32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            space_total: 70000000,
+            space_update: 30000000,
+        }
+    }
+}
+
+/// A line of the terminal log.
+enum LogLine<'a> {
+    /// "$ cd a", with the directory name.
+    Cd(&'a str),
+    /// "$ ls"
+    Ls,
+    /// "dir a", a directory entry of a listing.
+    Dir,
+    /// "14848514 b.txt", a file entry of a listing with the file size.
+    File(u32),
+}
+
+/// Parse the input data and fill up the tree accordingly.
+pub fn build_dirtree(lines: Vec<&str>) -> Result<DirTree, ParseError> {
+    // initialize tree
+    let mut dir_tree: DirTree = DirTree::default();
+    let root_idx = dir_tree.create_node("/".to_owned(), 0);
+    let mut currdir_idx = root_idx;
+
+    // build directory tree from input
+    let name = || take_while1(|ch| ch != ' ', "a name");
+    let log_line = alt((
+        map(preceded(tag("$ cd "), name()), LogLine::Cd),
+        map(tag("$ ls"), |_| LogLine::Ls),
+        map(preceded(tag("dir "), name()), |_| LogLine::Dir),
+        map(
+            terminated(uint("a file size"), preceded(tag(" "), name())),
+            LogLine::File,
+        ),
+    ));
+    for line in InputLine::numbered(lines) {
+        match parse_line(line, &log_line)? {
+            LogLine::Ls => {}      // we can ignore the "ls" command
+            LogLine::Dir => {}     // we can ignore directory entries until we cd there
+            LogLine::Cd("/") => {} // we already took care of the root folder
+            LogLine::Cd(dir_name @ "..") => {
+                currdir_idx = dir_tree.arena[currdir_idx]
+                    .parent
+                    .ok_or_else(|| line.error_at(dir_name, "a subdirectory of \"/\""))?;
+            } // go back to parent directory
+            LogLine::Cd(dir_name) => {
+                let node_idx = dir_tree.create_node(dir_name.to_owned(), 0);
+                dir_tree.arena[currdir_idx].children.push(node_idx);
+                dir_tree.arena[node_idx].parent = Some(currdir_idx);
+                currdir_idx = node_idx;
+            } // We update our tree when changing to a new directory. Currently,
+            // changing to directories already present in our tree is not handled
+            // properly here and would cause issues; luckily, this doesn't seem
+            // to happen in this example.
+            LogLine::File(size) => dir_tree.arena[currdir_idx].add_filesize(size),
+        }
+    }
+
+    Ok(dir_tree)
+}
+
+/// Solution to part 1: find the total size of all directories, each with sizes
+/// smaller than 100 kb.
+pub fn get_sum_lt100k(dir_tree: &DirTree) -> Result<u32> {
+    let mut sum_lt100k: u32 = 0;
+    for dir in &dir_tree.arena {
+        let mut total_size = dir.size;
+        add_subdir_sizes(dir, &mut total_size, dir_tree);
+        if total_size < 100000 {
+            sum_lt100k += total_size;
+        }
+    }
+    Ok(sum_lt100k)
+}
+
+/// Solution to part 2: find the size of the smallest folder that we can delete
+/// so that the update fits on the disk.
+pub fn find_dir_to_del(dir_tree: &DirTree, space_total: u32, space_update: u32) -> Result<u32> {
+    let mut used_space = dir_tree.arena[0].size;
+    add_subdir_sizes(&dir_tree.arena[0], &mut used_space, dir_tree);
+
+    let mut suitable_dir_sizes: Vec<u32> = Vec::new();
+    for dir in &dir_tree.arena {
+        let mut total_size = dir.size;
+        add_subdir_sizes(dir, &mut total_size, dir_tree);
+        if total_size > space_update.saturating_sub(space_total.saturating_sub(used_space)) {
+            suitable_dir_sizes.push(total_size)
+        }
+    }
+    suitable_dir_sizes
+        .into_iter()
+        .min()
+        .ok_or_else(|| anyhow!("Couldn't find suitable directory to delete."))
+}
+
+/// Function that recursively adds the size of subdirectories to the size of all files
+/// in the current directory, to get the cumulative directory size.
+fn add_subdir_sizes(dir: &dirtree::Node, total_size: &mut u32, dir_tree: &DirTree) {
+    let subdirs = dir.children.clone();
+    for subdir in subdirs {
+        *total_size += dir_tree.arena[subdir].size;
+        add_subdir_sizes(&dir_tree.arena[subdir], total_size, dir_tree);
+    }
+}
+
+/// Lowercase name of up to `max_len` letters for a generated file or directory.
+fn random_name(rng: &mut InputRng, max_len: usize) -> String {
+    let len = rng.gen_range(1..=max_len);
+    (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+}
+
+/// Append the terminal output of listing `dir` and then visiting each of its
+/// subdirectories to a generated transcript.
+fn write_listing(
+    dir: usize,
+    subdirs: &[Vec<usize>],
+    files: &[Vec<String>],
+    dir_names: &[String],
+    transcript: &mut String,
+) {
+    transcript.push_str("$ ls\n");
+    for &subdir in &subdirs[dir] {
+        *transcript += &format!("dir {}\n", dir_names[subdir]);
+    }
+    for file in &files[dir] {
+        *transcript += &format!("{file}\n");
+    }
+    for &subdir in &subdirs[dir] {
+        *transcript += &format!("$ cd {}\n", dir_names[subdir]);
+        write_listing(subdir, subdirs, files, dir_names, transcript);
+        transcript.push_str("$ cd ..\n");
+    }
+}
+
+/// Check if algorithm for part 1 works on test input (see puzzle description).
+#[test]
+fn test_sum_lt100k_exampledata_part1() {
+    // inline test data
+    let lines = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+
+    let dir_tree = build_dirtree(lines).unwrap();
+    assert_eq!(get_sum_lt100k(&dir_tree).unwrap(), 95437);
+}
+
+/// Check if algorithm for part 2 works on test input (see puzzle description).
+#[test]
+fn test_find_dir_to_del_part2() {
+    // inline test data
+    let lines = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+
+    let dir_tree = build_dirtree(lines).unwrap();
+    let params = Params::default();
+    let dir_size = find_dir_to_del(&dir_tree, params.space_total, params.space_update);
+    assert_eq!(dir_size.unwrap(), 24933642);
+}
+
+aoc_common::example_tests!(Day07);
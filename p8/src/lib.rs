@@ -0,0 +1,173 @@
+//! Advent of Code day 8
+//! <https://adventofcode.com/2022/day/8>
+//!
+//! Helping the elves with their tree house was straightforward enough.
+//! The forest is a `Grid` from aoc_common, so looking along a line of sight
+//! is just a ray from the tree in one of the four directions.
+#![warn(missing_docs)]
+
+use anyhow::Result;
+use aoc_common::{
+    frame_stride, Answer, Frame, GenerateInput, Grid, InputRng, NoParams, ParseError, Rgb,
+    Solution, Visualize, DIRECTIONS_4,
+};
+use rand::Rng;
+
+/// Day 8: Treetop Tree House.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Params = NoParams;
+    type Input<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Grid<u32>> {
+        Ok(parse_forest(input)?)
+    }
+
+    fn part1(forest: &Grid<u32>, _params: &NoParams) -> Result<Answer> {
+        Ok(nr_visible_trees(forest).into())
+    }
+
+    fn part2(forest: &Grid<u32>, _params: &NoParams) -> Result<Answer> {
+        Ok(max_scenic_score(forest).into())
+    }
+}
+
+/// `size` is the width and height of the (square) forest.
+impl GenerateInput for Day08 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                let row = (0..side).map(|_| rng.gen_range('0'..='9'));
+                row.chain(['\n']).collect::<String>()
+            })
+            .collect()
+    }
+}
+
+/// Colour of the tree with the best scenic score in the visualisation.
+const BEST_TREE: Rgb = [255, 200, 40];
+
+/// The trees visible from outside the forest turning green row by row, and finally the
+/// tree with the best scenic score.
+impl Visualize for Day08 {
+    fn frames(forest: &Grid<u32>, _params: &NoParams) -> Result<Vec<Frame>> {
+        let mut frame = forest.map(|&height| tree_colour(height, false));
+        let stride = frame_stride(forest.height());
+        let mut frames = Vec::new();
+        for y in 0..forest.height() {
+            if y % stride == 0 {
+                frames.push(frame.clone());
+            }
+            for x in 0..forest.width() {
+                if is_visible(forest, (x, y)) {
+                    frame[(x, y)] = tree_colour(forest[(x, y)], true);
+                }
+            }
+        }
+        if let Some(best) = forest
+            .positions()
+            .max_by_key(|&pos| get_scenic_score(forest, pos))
+        {
+            frame[best] = BEST_TREE;
+        }
+        frames.push(frame);
+        Ok(frames)
+    }
+
+    /// The tree heights, with "*" for the tree with the best scenic score.
+    fn glyph(colour: Rgb) -> char {
+        (0..10)
+            .find(|&height| {
+                [true, false]
+                    .map(|visible| tree_colour(height, visible))
+                    .contains(&colour)
+            })
+            .map_or('*', |height| char::from_digit(height, 10).unwrap())
+    }
+}
+
+/// Colour of a tree of the given height: green if it is visible from outside the
+/// forest, grey otherwise.
+fn tree_colour(height: u32, visible: bool) -> Rgb {
+    let shade = (height * 15) as u8;
+    if visible {
+        [20 + shade / 3, 80 + shade, 30]
+    } else {
+        [50 + shade / 2; 3]
+    }
+}
+
+/// parse forest into a grid of tree heights.
+pub fn parse_forest(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, "a tree height digit", |ch| ch.to_digit(10))
+}
+
+/// Determine if the tree at `pos` is visible from outside the grid, i.e. if all trees
+/// between it and the edge are smaller in at least one direction.
+fn is_visible(forest: &Grid<u32>, pos: (usize, usize)) -> bool {
+    let tree_size = forest[pos];
+    DIRECTIONS_4
+        .iter()
+        .any(|&dir| forest.ray(pos, dir).all(|&other| other < tree_size))
+}
+
+/// Calculate number of visible trees.
+pub fn nr_visible_trees(forest: &Grid<u32>) -> usize {
+    forest
+        .positions()
+        .filter(|&pos| is_visible(forest, pos))
+        .count()
+}
+
+/// Find grid distance of closest tree bigger than or as big as the tree
+/// at `pos` along direction `dir`, or the distance to the edge if there is none.
+fn viewing_distance(forest: &Grid<u32>, pos: (usize, usize), dir: (isize, isize)) -> usize {
+    let tree_size = forest[pos];
+    let mut distance = 0;
+    for &other in forest.ray(pos, dir) {
+        distance += 1; // elves count the blocking tree as well :-)
+        if other >= tree_size {
+            break;
+        }
+    }
+    distance
+}
+
+/// Calculate scenic score from distances determined by the "viewing_distance()" function.
+fn get_scenic_score(forest: &Grid<u32>, pos: (usize, usize)) -> usize {
+    DIRECTIONS_4
+        .iter()
+        .map(|&dir| viewing_distance(forest, pos, dir))
+        .product()
+}
+
+/// Solution for part 2: the highest scenic score of any tree in the forest.
+pub fn max_scenic_score(forest: &Grid<u32>) -> usize {
+    forest
+        .positions()
+        .map(|pos| get_scenic_score(forest, pos))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Test for part 1.
+#[test]
+fn check_find_visible_trees_on_testdata() {
+    let forest = parse_forest(include_str!("../input_test.txt")).unwrap();
+    assert_eq!(nr_visible_trees(&forest), 21)
+}
+
+/// Test for part 2.
+#[test]
+fn check_find_viewblockers_on_testdata() {
+    let forest = parse_forest(include_str!("../input_test.txt")).unwrap();
+
+    assert_eq!(get_scenic_score(&forest, (2, 1)), 4);
+    assert_eq!(get_scenic_score(&forest, (2, 3)), 8);
+    assert_eq!(max_scenic_score(&forest), 8);
+}
+
+aoc_common::example_tests!(Day08);
@@ -0,0 +1,270 @@
+//! Advent of Code day 9
+//! <https://adventofcode.com/2022/day/9>
+//!
+//! This one was awful and took way longer than it should have.
+//! I quickly found a solution for part 1, which relied on the fact that for the 2-node
+//! rope, following the head is equivalent to move the tail to the heads' previous position.
+//! I couldn't make this wor for part 2 though (not sure if possible?)...
+//! after much playing around, I broke down, hand-coded the cases and only could make
+//! stuff work after a lot of debugging. Perhaps it would have been easier to follow the
+//! advice and visualize the rope movement, but I don't think so, mostly I was dealing
+//! with missing cases causing a panic anyway.
+//!
+//! I ended up with very imperative code doing a lot of in-place vector mutation.
+//! In the end I am fed up sufficiently with this puzzle that I didn't bother to spend
+//! a lot of time cleaning the code up after finding a solution.
+//!
+//! Later on, the shared "Vec2" type boiled the hand-coded cases down to a single rule:
+//! a node that is more than one step away from its predecessor moves one step towards it
+//! along each axis.
+#![warn(missing_docs)]
+
+use std::iter;
+
+use anyhow::Result;
+use aoc_common::combinators::{map, one_of, parse_line, separated_pair, tag, uint};
+use aoc_common::{
+    frame_stride, Answer, Frame, GenerateInput, InputLine, InputRng, NoParams, ParseError, Rgb,
+    Solution, Vec2, Visualize,
+};
+use itertools::Itertools; // for "unique()" iterator adaptor
+
+/// Day 9: Rope Bridge.
+pub struct Day09;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Params = NoParams;
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Vec<Direction>> {
+        Ok(parse_moves(input.lines().collect())?)
+    }
+
+    // part 1: only 2 nodes
+    fn part1(rope_moves: &Vec<Direction>, _params: &NoParams) -> Result<Answer> {
+        let rope_nodes = initialize_rope(2);
+        Ok(simulate_rope_tail(rope_moves, rope_nodes).len().into())
+    }
+
+    // part 2: 10 nodes
+    fn part2(rope_moves: &Vec<Direction>, _params: &NoParams) -> Result<Answer> {
+        let rope_nodes = initialize_rope(10);
+        Ok(simulate_rope_tail(rope_moves, rope_nodes).len().into())
+    }
+}
+
+/// `size` is the number of moves.
+impl GenerateInput for Day09 {
+    fn generate_input(rng: &mut InputRng, size: usize, _params: &NoParams) -> String {
+        (0..size)
+            .map(|_| {
+                let direction = ["R", "L", "U", "D"].choose(rng).unwrap();
+                format!("{direction} {}\n", rng.gen_range(1..=20))
+            })
+            .collect()
+    }
+}
+
+/// Colours of the visualisation: the bridge, and where the tail has been.
+const BRIDGE: Rgb = [24, 24, 24];
+const TRAIL: Rgb = [60, 110, 160];
+/// Colour of the head, the knots get greyer towards the tail.
+const HEAD: Rgb = [230, 60, 40];
+
+/// Colour of the knot `idx` of the rope.
+fn knot_colour(idx: usize) -> Rgb {
+    match idx {
+        0 => HEAD,
+        _ => [(240 - 10 * idx.min(9)) as u8; 3],
+    }
+}
+
+/// The rope of part 2 moving about, leaving a trail where its tail has been.
+impl Visualize for Day09 {
+    fn frames(rope_moves: &Vec<Direction>, _params: &NoParams) -> Result<Vec<Frame>> {
+        let mut rope_nodes = initialize_rope(10);
+        let mut ropes = vec![rope_nodes.iter().map(|node| node.pos).collect::<Vec<_>>()];
+        for rope_move in rope_moves {
+            update_nodes(&mut rope_nodes, *rope_move);
+            ropes.push(rope_nodes.iter().map(|node| node.pos).collect());
+        }
+
+        // the frame covers every position any node ever reaches
+        let (xs, ys): (Vec<_>, Vec<_>) = ropes.iter().flatten().map(|pos| (pos.x, pos.y)).unzip();
+        let (x_min, y_min) = (*xs.iter().min().unwrap(), *ys.iter().min().unwrap());
+        let (x_max, y_max) = (*xs.iter().max().unwrap(), *ys.iter().max().unwrap());
+        let cell = |pos: Pos| ((pos.x - x_min) as usize, (pos.y - y_min) as usize);
+
+        let mut trail = Frame::new(
+            (x_max - x_min + 1) as usize,
+            (y_max - y_min + 1) as usize,
+            BRIDGE,
+        );
+        let stride = frame_stride(ropes.len());
+        let mut frames = Vec::new();
+        for (idx, rope) in ropes.iter().enumerate() {
+            trail[cell(*rope.last().unwrap())] = TRAIL;
+            if idx % stride == 0 || idx == ropes.len() - 1 {
+                let mut frame = trail.clone();
+                for (node_idx, pos) in rope.iter().enumerate().rev() {
+                    frame[cell(*pos)] = knot_colour(node_idx);
+                }
+                frames.push(frame);
+            }
+        }
+        Ok(frames)
+    }
+
+    /// "H" for the head and numbers for the other knots, like in the puzzle description.
+    fn glyph(colour: Rgb) -> char {
+        match (0..10).find(|&idx| knot_colour(idx) == colour) {
+            Some(0) => 'H',
+            Some(idx) => char::from_digit(idx as u32, 10).unwrap(),
+            None if colour == TRAIL => '#',
+            None => '.',
+        }
+    }
+}
+
+/// Position of a rope node.
+pub type Pos = Vec2<i32>;
+
+/// Represents a rope node. Yes, a struct with a single struct as field seems
+/// quite useless. It previously had also a "previous position" state that
+/// turned out useless in the end, and now I don't feel like cleaning this up.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Node {
+    pos: Pos,
+}
+
+/// Direction the head of the rope moves in, one step at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// "R"
+    Right,
+    /// "L"
+    Left,
+    /// "U"
+    Up,
+    /// "D"
+    Down,
+}
+
+impl Direction {
+    /// A single step in this direction.
+    pub fn step(self) -> Pos {
+        match self {
+            Direction::Right => Pos::RIGHT,
+            Direction::Left => Pos::LEFT,
+            Direction::Up => Pos::UP,
+            Direction::Down => Pos::DOWN,
+        }
+    }
+}
+
+/// Parses input into instruction vector, a direction per step.
+pub fn parse_moves(lines: Vec<&str>) -> Result<Vec<Direction>, ParseError> {
+    let mut moves = Vec::new();
+    for line in InputLine::numbered(lines) {
+        let direction = map(
+            one_of(
+                &["R", "L", "U", "D"],
+                "a direction (\"R\", \"L\", \"U\" or \"D\")",
+            ),
+            |dir| match dir {
+                "R" => Direction::Right,
+                "L" => Direction::Left,
+                "U" => Direction::Up,
+                _ => Direction::Down,
+            },
+        );
+        let step = separated_pair(direction, tag(" "), uint::<usize>("a step number"));
+        let (dir, nr) = parse_line(line, step)?;
+        moves.extend(iter::repeat_n(dir, nr));
+    }
+    Ok(moves)
+}
+
+/// Builds vector containing "node_nr" nodes to represent a node.
+pub fn initialize_rope(node_nr: u32) -> Vec<Node> {
+    let mut rope_nodes: Vec<Node> = Vec::new();
+    let node: Node = Default::default();
+    for _idx in 0..node_nr {
+        rope_nodes.push(node);
+    }
+    rope_nodes
+}
+
+/// Moves head, then moves other nodes accordingly.
+fn update_nodes(rope_nodes: &mut [Node], rope_move: Direction) {
+    rope_nodes[0].pos += rope_move.step();
+
+    for idx in 1..rope_nodes.len() {
+        let node_diff = rope_nodes[idx - 1].pos - rope_nodes[idx].pos;
+        // nodes touching (also diagonally or by overlapping) don't need to move
+        if rope_nodes[idx - 1].pos.chebyshev(rope_nodes[idx].pos) > 1 {
+            rope_nodes[idx].pos += node_diff.signum();
+        }
+    }
+}
+
+/// Collects head (for debugging purposes) and tail positions in vectors,
+/// filter for unique positions and return them.
+pub fn simulate_rope_tail(rope_moves: &[Direction], mut rope_nodes: Vec<Node>) -> Vec<Pos> {
+    let mut tail_tracker: Vec<Node> = Vec::new();
+    let mut head_tracker: Vec<Node> = Vec::new();
+
+    for rope_move in rope_moves {
+        update_nodes(&mut rope_nodes, *rope_move);
+        head_tracker.push(*rope_nodes.first().unwrap());
+        tail_tracker.push(*rope_nodes.last().unwrap());
+    }
+
+    let tail_pos: Vec<_> = tail_tracker
+        .iter()
+        .map(|ele| ele.pos) // map node structures to their positions
+        .unique()
+        .collect();
+    tail_pos
+}
+
+#[test]
+fn test_tail_tracking_on_example_input_part1() {
+    let lines = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+    let rope_moves = parse_moves(lines).unwrap();
+
+    let rope_nodes = initialize_rope(2);
+    let tail_pos = simulate_rope_tail(&rope_moves, rope_nodes);
+    assert_eq!(tail_pos.len(), 13)
+}
+
+#[test]
+fn test_tail_tracking_on_example_input_part2() {
+    let lines = include_str!("../input_test.txt")
+        .lines()
+        .collect::<Vec<_>>();
+    let rope_moves = parse_moves(lines).unwrap();
+
+    let rope_nodes = initialize_rope(10);
+    let tail_pos = simulate_rope_tail(&rope_moves, rope_nodes);
+    assert_eq!(tail_pos.len(), 1)
+}
+
+#[test]
+fn test_tail_tracking_on_larger_example_input_part2() {
+    let lines = include_str!("../input_test2.txt")
+        .lines()
+        .collect::<Vec<_>>();
+    let rope_moves = parse_moves(lines).unwrap();
+
+    let rope_nodes = initialize_rope(10);
+    let tail_pos = simulate_rope_tail(&rope_moves, rope_nodes);
+    assert_eq!(tail_pos.len(), 36)
+}
+
+aoc_common::example_tests!(Day09);